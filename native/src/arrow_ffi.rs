@@ -0,0 +1,133 @@
+use anyhow::{anyhow, Result};
+
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::{Array, ArrayRef, RecordBatch, StructArray};
+use datafusion::arrow::datatypes::{Field, Fields, Schema, SchemaRef};
+
+/// Imports a schema exported through the Arrow C Data Interface.
+/// The schema must describe a struct whose fields are the record batch columns.
+pub(crate) fn import_schema(ffi_schema: &FFI_ArrowSchema) -> Result<SchemaRef> {
+    Schema::try_from(ffi_schema)
+        .map(std::sync::Arc::new)
+        .map_err(|e| anyhow!("Failed to import schema from FFI: {e}"))
+}
+
+/// Imports a record batch exported through the Arrow C Data Interface as a struct array.
+///
+/// Takes ownership of `ffi_array`; it is released when the imported data is dropped.
+pub(crate) fn import_batch(ffi_array: FFI_ArrowArray, ffi_schema: &FFI_ArrowSchema, schema: &SchemaRef) -> Result<RecordBatch> {
+    let data = unsafe { arrow_array::ffi::from_ffi(ffi_array, ffi_schema) }
+        .map_err(|e| anyhow!("Failed to import record batch from FFI: {e}"))?;
+
+    let struct_array = StructArray::from(data);
+    if struct_array.null_count() > 0 {
+        return Err(anyhow!("Record batch struct array must not contain nulls"));
+    }
+
+    let (_, columns, _) = struct_array.into_parts();
+    RecordBatch::try_new(std::sync::Arc::clone(schema), columns)
+        .map_err(|e| anyhow!("Failed to build record batch: {e}"))
+}
+
+/// Takes ownership of `num_batches` contiguous arrays, leaving the caller's structs released.
+///
+/// The arrays are released when the returned values are dropped, so they must be taken before any
+/// validation that can fail.
+///
+/// # Safety
+/// - `batches_ptr` must point to `num_batches` valid `FFI_ArrowArray` structs, or be null when `num_batches` is 0
+pub(crate) unsafe fn take_arrays(batches_ptr: *mut FFI_ArrowArray, num_batches: usize) -> Result<Vec<FFI_ArrowArray>> {
+    if num_batches == 0 {
+        return Ok(Vec::new());
+    }
+    if batches_ptr.is_null() {
+        return Err(anyhow!("Batches pointer is null"));
+    }
+
+    Ok((0..num_batches)
+        .map(|i| unsafe { FFI_ArrowArray::from_raw(batches_ptr.add(i)) })
+        .collect())
+}
+
+/// Imports record batches exported as struct arrays. Every array is released even if one fails to import.
+pub(crate) fn import_batches(ffi_arrays: Vec<FFI_ArrowArray>, ffi_schema: &FFI_ArrowSchema, schema: &SchemaRef) -> Result<Vec<RecordBatch>> {
+    ffi_arrays.into_iter()
+        .map(|a| import_batch(a, ffi_schema, schema))
+        .collect()
+}
+
+/// Imports a single array exported through the Arrow C Data Interface.
+///
+/// Takes ownership of `ffi_array`; it is released when the imported data is dropped.
+pub(crate) fn import_array(ffi_array: FFI_ArrowArray, ffi_schema: &FFI_ArrowSchema) -> Result<ArrayRef> {
+    let data = unsafe { arrow_array::ffi::from_ffi(ffi_array, ffi_schema) }
+        .map_err(|e| anyhow!("Failed to import array from FFI: {e}"))?;
+
+    Ok(arrow_array::make_array(data))
+}
+
+/// Exports columns as a single struct array with fields named `arg0`, `arg1`, ... through the Arrow C Data Interface.
+///
+/// All fields are marked nullable. `num_rows` is used as the struct length, so zero columns are supported.
+pub(crate) fn export_columns(columns: Vec<ArrayRef>, num_rows: usize) -> Result<(FFI_ArrowArray, FFI_ArrowSchema)> {
+    let fields = columns.iter()
+        .enumerate()
+        .map(|(i, c)| Field::new(format!("arg{i}"), c.data_type().clone(), true))
+        .collect::<Fields>();
+
+    let struct_array = StructArray::try_new_with_length(fields, columns, None, num_rows)
+        .map_err(|e| anyhow!("Failed to build argument struct array: {e}"))?;
+    let ffi_schema = FFI_ArrowSchema::try_from(struct_array.data_type())
+        .map_err(|e| anyhow!("Failed to export argument schema to FFI: {e}"))?;
+
+    Ok((FFI_ArrowArray::new(&struct_array.to_data()), ffi_schema))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Int64Array, StringArray};
+
+    use super::*;
+
+    fn sample_batch() -> RecordBatch {
+        RecordBatch::try_from_iter([
+            ("id", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+            ("name", Arc::new(StringArray::from(vec![Some("a"), None])) as ArrayRef)
+        ]).unwrap()
+    }
+
+    fn export_batch(batch: &RecordBatch) -> FFI_ArrowArray {
+        FFI_ArrowArray::new(&StructArray::from(batch.clone()).to_data())
+    }
+
+    #[test]
+    fn take_arrays_releases_caller_structs() {
+        let batch = sample_batch();
+        let mut arrays = [export_batch(&batch), export_batch(&batch)];
+
+        let taken = unsafe { take_arrays(arrays.as_mut_ptr(), arrays.len()) }.unwrap();
+
+        assert_eq!(taken.len(), 2);
+        assert!(arrays.iter().all(FFI_ArrowArray::is_released));
+        assert!(!taken.iter().any(FFI_ArrowArray::is_released));
+    }
+
+    #[test]
+    fn take_arrays_rejects_null_pointer_only_when_batches_are_expected() {
+        assert!(unsafe { take_arrays(std::ptr::null_mut(), 0) }.unwrap().is_empty());
+        assert!(unsafe { take_arrays(std::ptr::null_mut(), 1) }.is_err());
+    }
+
+    #[test]
+    fn import_batches_round_trips_struct_arrays() {
+        let batch = sample_batch();
+        let ffi_schema = FFI_ArrowSchema::try_from(batch.schema().as_ref()).unwrap();
+        let schema = import_schema(&ffi_schema).unwrap();
+
+        let batches = import_batches(vec![export_batch(&batch), export_batch(&batch)], &ffi_schema, &schema).unwrap();
+
+        assert_eq!(batches, vec![batch.clone(), batch]);
+    }
+}
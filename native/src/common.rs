@@ -19,6 +19,13 @@ impl BytesData {
         }
     }
 
+    pub(crate) fn empty() -> Self {
+        BytesData {
+            data: std::ptr::null(),
+            len: 0,
+        }
+    }
+
     pub(crate) fn as_opt_slice(&self) -> Option<&[u8]> {
         if self.data.is_null() {
             None
@@ -50,6 +57,16 @@ pub(crate) fn invoke_callback<T>(result: Result<T, crate::ErrorInfo>, callback:
     }
 }
 
+pub(crate) fn invoke_callback_message<M: prost::Message>(result: Result<M, crate::ErrorInfo>, callback: Callback, user_data: u64) {
+    match result {
+        Ok(message) => {
+            let bytes = message.encode_to_vec();
+            invoke_callback_success(BytesData::new(&bytes), callback, user_data);
+        },
+        Err(error) => invoke_callback_error(&error, callback, user_data)
+    }
+}
+
 #[allow(clippy::needless_pass_by_value)]
 pub(crate) fn invoke_callback_success<T>(result: T, callback: Callback, user_data: u64) {
     let value_ptr = (&raw const result).cast::<std::ffi::c_void>();
@@ -1,10 +1,17 @@
 use std::sync::Arc;
 use prost::Message;
+use datafusion::catalog::{CatalogProvider, SchemaProvider};
+use datafusion::common::config::Dialect;
+use datafusion::logical_expr::LogicalPlan;
+use datafusion::prelude::{DataFrame, SQLOptions};
+use datafusion::sql::parser::Statement;
 
 use crate::proto;
 
 use crate::{
+    arrow_ffi,
     mappers,
+    streaming,
     ErrorCode,
     ErrorInfo
 };
@@ -15,29 +22,141 @@ pub struct SessionContextWrapper {
 }
 
 impl SessionContextWrapper {
-    fn new(runtime: crate::RuntimeHandle) -> Self {
+    fn new(
+        runtime: crate::RuntimeHandle,
+        config: datafusion::prelude::SessionConfig,
+        runtime_env: Arc<datafusion::execution::runtime_env::RuntimeEnv>
+    ) -> Self {
         Self {
             runtime,
-            inner: Arc::new(datafusion::prelude::SessionContext::new())
+            inner: Arc::new(datafusion::prelude::SessionContext::new_with_config_rt(config, runtime_env))
         }
     }
+
+    pub(crate) fn inner(&self) -> &datafusion::prelude::SessionContext {
+        &self.inner
+    }
+
+    pub(crate) fn runtime(&self) -> &crate::RuntimeHandle {
+        &self.runtime
+    }
+
+    pub(crate) fn shared_inner(&self) -> Arc<datafusion::prelude::SessionContext> {
+        Arc::clone(&self.inner)
+    }
+
+    /// Parses and plans a SQL statement with `dialect`, or the context dialect if `None`.
+    /// Parse errors name the dialect that was in effect.
+    pub(crate) async fn plan_sql(&self, sql: &str, dialect: Option<Dialect>) -> Result<LogicalPlan, ErrorInfo> {
+        let state = self.inner.state();
+        let dialect = dialect.unwrap_or(state.config().options().sql_parser.dialect);
+
+        let statement = state
+            .sql_to_statement(sql, &dialect)
+            .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, format!("Failed to parse SQL with dialect '{dialect}': {e}")))?;
+
+        self.plan_statement(statement).await
+    }
+
+    /// Plans a SQL statement with the named dialect, or the context dialect if `None`, and checks the plan against `options`.
+    pub(crate) async fn plan_sql_with_options(&self, sql: &str, options: Option<&SQLOptions>, dialect: Option<&str>) -> Result<LogicalPlan, ErrorInfo> {
+        let dialect = dialect.map(parse_dialect).transpose()?;
+        let plan = self.plan_sql(sql, dialect).await?;
+
+        Self::verify_plan(&plan, options)?;
+        Ok(plan)
+    }
+
+    /// Plans a parsed SQL statement.
+    pub(crate) async fn plan_statement(&self, statement: Statement) -> Result<LogicalPlan, ErrorInfo> {
+        self.inner
+            .state()
+            .statement_to_plan(statement)
+            .await
+            .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e))
+    }
+
+    /// Plans and runs a SQL statement like `SessionContext::sql_with_options`, but reports statements rejected
+    /// by `options` with `ErrorCode::StatementNotAllowed`.
+    pub(crate) async fn run_sql(&self, sql: &str, options: Option<&SQLOptions>, dialect: Option<Dialect>) -> Result<DataFrame, ErrorInfo> {
+        let plan = self.plan_sql(sql, dialect).await?;
+        self.run_plan(plan, options).await
+    }
+
+    /// Checks a plan against `options`, reporting rejected statements with `ErrorCode::StatementNotAllowed`.
+    pub(crate) fn verify_plan(plan: &LogicalPlan, options: Option<&SQLOptions>) -> Result<(), ErrorInfo> {
+        options.map_or(Ok(()), |options| options.verify_plan(plan))
+            .map_err(|e| ErrorInfo::new(ErrorCode::StatementNotAllowed, e))
+    }
+
+    /// Runs a plan like `SessionContext::execute_logical_plan` after checking it against `options`.
+    pub(crate) async fn run_plan(&self, plan: LogicalPlan, options: Option<&SQLOptions>) -> Result<DataFrame, ErrorInfo> {
+        Self::verify_plan(&plan, options)?;
+
+        self.inner
+            .execute_logical_plan(plan)
+            .await
+            .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e))
+    }
+
+    fn catalog(&self, catalog_name: &str) -> Result<Arc<dyn CatalogProvider>, ErrorInfo> {
+        self.inner
+            .catalog(catalog_name)
+            .ok_or_else(|| ErrorInfo::new(ErrorCode::CatalogError, format!("Catalog '{catalog_name}' does not exist")))
+    }
+
+    fn schema(&self, catalog_name: &str, schema_name: &str) -> Result<Arc<dyn SchemaProvider>, ErrorInfo> {
+        self.catalog(catalog_name)?
+            .schema(schema_name)
+            .ok_or_else(|| ErrorInfo::new(ErrorCode::CatalogError, format!("Schema '{catalog_name}.{schema_name}' does not exist")))
+    }
 }
 
 /// Creates a new `SessionContext` bound to a runtime.
 ///
+/// Returns `ErrorCode::InvalidArgument` if the configuration cannot be decoded or contains unknown or invalid options.
+///
 /// # Safety
 /// - `runtime_ptr` must be a valid pointer returned by `datafusion_runtime_new`
+/// - `config_bytes` must be a valid `BytesData` containing a protobuf-encoded `SessionConfig`, or empty for defaults
+/// - `runtime_env_bytes` must be a valid `BytesData` containing a protobuf-encoded `RuntimeEnvConfig`, or empty for
+///   an unbounded memory pool and the default disk manager
 /// - `context_ptr` must be a valid, aligned, non-null pointer to writable memory
 /// - Caller must call `datafusion_context_destroy` exactly once with the returned pointer
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn datafusion_context_new(runtime_ptr: *mut crate::RuntimeHandle, context_ptr: *mut *mut SessionContextWrapper) -> ErrorCode {
+pub unsafe extern "C" fn datafusion_context_new(
+    runtime_ptr: *mut crate::RuntimeHandle,
+    config_bytes: crate::BytesData,
+    runtime_env_bytes: crate::BytesData,
+    context_ptr: *mut *mut SessionContextWrapper
+) -> ErrorCode {
     if context_ptr.is_null() {
         return ErrorCode::InvalidArgument;
     }
 
     let runtime_handle = ffi_ref!(runtime_ptr);
 
-    let context = Box::new(SessionContextWrapper::new(Arc::clone(runtime_handle)));
+    let config = config_bytes.as_opt_slice()
+        .map(proto::SessionConfig::decode)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("Failed to decode session config: {e}"))
+        .and_then(|c| mappers::from_proto_session_config(c.as_ref()));
+
+    let runtime_env = runtime_env_bytes.as_opt_slice()
+        .map(proto::RuntimeEnvConfig::decode)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("Failed to decode runtime environment config: {e}"))
+        .and_then(|c| mappers::from_proto_runtime_env(c.as_ref()));
+
+    let (config, runtime_env) = match config.and_then(|c| runtime_env.map(|r| (c, r))) {
+        Ok(config) => config,
+        Err(err) => {
+            dev_msg!("Error creating context: {}", err);
+            return ErrorCode::InvalidArgument;
+        }
+    };
+
+    let context = Box::new(SessionContextWrapper::new(Arc::clone(runtime_handle), config, runtime_env));
     unsafe { *context_ptr = Box::into_raw(context); }
 
     dev_msg!("Successfully created context: {:p}", unsafe { *context_ptr });
@@ -61,6 +180,32 @@ pub unsafe extern "C" fn datafusion_context_destroy(context_ptr: *mut SessionCon
     ErrorCode::Ok
 }
 
+/// Protobuf read options of a file format together with their parsed schema, which the `DataFusion`
+/// read options borrow.
+struct FileReadOptions<P> {
+    options: Option<P>,
+    schema: Result<Option<datafusion::arrow::datatypes::Schema>, ErrorInfo>
+}
+
+impl<P: mappers::ProtoReadOptions + Message + Default> FileReadOptions<P> {
+    /// Decodes the options from `bytes`, or returns `None` if they are not a valid message.
+    fn decode(bytes: &crate::BytesData) -> Option<Self> {
+        let options = bytes.as_opt_slice().map(P::decode).transpose().ok()?;
+        let schema = mappers::from_proto_schema(options.as_ref().and_then(P::schema))
+            .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to parse {} schema from options: {e}", P::FORMAT)));
+
+        Some(Self { options, schema })
+    }
+
+    /// Converts the options into `DataFusion` read options, reporting an invalid schema or option as `InvalidArgument`.
+    fn to_read_options(&self) -> Result<P::Options<'_>, ErrorInfo> {
+        let schema = self.schema.as_ref().map_err(Clone::clone)?;
+
+        P::to_read_options(self.options.as_ref(), schema.as_ref())
+            .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to convert {} options: {e}", P::FORMAT)))
+    }
+}
+
 /// Registers a CSV file as a table in the `SessionContext`.
 ///
 /// This is an async operation. The callback is invoked on completion with no result data.
@@ -83,43 +228,22 @@ pub unsafe extern "C" fn datafusion_context_register_csv(
     let context = ffi_ref!(context_ptr);
     let table_ref = ffi_cstr_to_string!(table_ref_ptr);
     let table_path = ffi_cstr_to_string!(table_path_ptr);
-
-    let csv_options_proto = match csv_options_bytes.as_opt_slice() {
-        Some(b) => match proto::CsvReadOptions::decode(b) {
-            Ok(opts) => Some(opts),
-            Err(_) => return ErrorCode::InvalidArgument
-        },
-        None => None
-    };
+    let Some(options) = FileReadOptions::<proto::CsvReadOptions>::decode(&csv_options_bytes) else { return ErrorCode::InvalidArgument };
 
     dev_msg!("Registering CSV table '{}' from path '{}'", table_ref, table_path);
 
-    context.runtime.spawn(async move {
-        let schema_opt = match mappers::from_proto_schema(
-            csv_options_proto.as_ref().and_then(|o| o.schema.as_ref())
-        ) {
-            Ok(s) => s,
-            Err(e) => {
-                let error_info = ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to parse CSV schema from options: {e}"));
-                crate::invoke_callback_error(&error_info, callback, user_data);
-                return;
-            }
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let result = match options.to_read_options() {
+            Ok(opts) => context.inner
+                .register_csv(&table_ref, &table_path, opts)
+                .await
+                .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::TableRegistrationFailed, e)),
+            Err(e) => Err(e)
         };
-        
-        match mappers::from_proto_csv_options(csv_options_proto.as_ref(), schema_opt.as_ref()) {
-            Ok(opts) => {
-                let result = context.inner
-                    .register_csv(&table_ref, &table_path, opts)
-                    .await
-                    .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e));
-
-                crate::invoke_callback(result, callback, user_data);
-            },
-            Err(e) => {
-                let error_info = ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to convert CSV options: {e}"));
-                crate::invoke_callback_error(&error_info, callback, user_data);
-            }
-        }
+
+        crate::invoke_callback(result, callback, user_data);
+
+        dev_msg!("Finished registering CSV table '{}' from path '{}'", table_ref, table_path);
     });
 
     ErrorCode::Ok
@@ -147,43 +271,21 @@ pub unsafe extern "C" fn datafusion_context_register_json(
     let context = ffi_ref!(context_ptr);
     let table_ref = ffi_cstr_to_string!(table_ref_ptr);
     let table_path = ffi_cstr_to_string!(table_path_ptr);
-
-    let json_options_proto = match json_options_bytes.as_opt_slice() {
-        Some(b) => match proto::JsonReadOptions::decode(b) {
-            Ok(opts) => Some(opts),
-            Err(_) => return ErrorCode::InvalidArgument
-        },
-        None => None
-    };
+    let Some(options) = FileReadOptions::<proto::JsonReadOptions>::decode(&json_options_bytes) else { return ErrorCode::InvalidArgument };
 
     dev_msg!("Registering JSON table '{}' from path '{}'", table_ref, table_path);
 
-    context.runtime.spawn(async move {
-        let schema_opt = match mappers::from_proto_schema(
-            json_options_proto.as_ref().and_then(|o| o.schema.as_ref())
-        ) {
-            Ok(s) => s,
-            Err(e) => {
-                let error_info = ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to parse JSON schema from options: {e}"));
-                crate::invoke_callback_error(&error_info, callback, user_data);
-                return;
-            }
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let result = match options.to_read_options() {
+            Ok(opts) => context.inner
+                .register_json(&table_ref, &table_path, opts)
+                .await
+                .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::TableRegistrationFailed, e)),
+            Err(e) => Err(e)
         };
 
-        match mappers::from_proto_json_read_options(json_options_proto.as_ref(), schema_opt.as_ref()) {
-            Ok(opts) => {
-                let result = context.inner
-                    .register_json(&table_ref, &table_path, opts)
-                    .await
-                    .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e));
-
-                crate::invoke_callback(result, callback, user_data);
-            },
-            Err(e) => {
-                let error_info = ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to convert JSON options: {e}"));
-                crate::invoke_callback_error(&error_info, callback, user_data);
-            }
-        }
+        crate::invoke_callback(result, callback, user_data);
+
         dev_msg!("Finished registering JSON table '{}' from path '{}'", table_ref, table_path);
     });
 
@@ -198,111 +300,1076 @@ pub unsafe extern "C" fn datafusion_context_register_json(
 /// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
 /// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
 /// - `table_path_ptr` must be a valid null-terminated UTF-8 string
+/// - `parquet_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `ParquetReadOptions`, or null
 /// - `callback` must be valid to call from any thread
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn datafusion_context_register_parquet(
     context_ptr: *mut SessionContextWrapper,
     table_ref_ptr: *const std::ffi::c_char,
     table_path_ptr: *const std::ffi::c_char,
+    parquet_options_bytes: crate::BytesData,
     callback: crate::Callback,
     user_data: u64
 ) -> ErrorCode {
     let context = ffi_ref!(context_ptr);
     let table_ref = ffi_cstr_to_string!(table_ref_ptr);
     let table_path = ffi_cstr_to_string!(table_path_ptr);
+    let Some(options) = FileReadOptions::<proto::ParquetReadOptions>::decode(&parquet_options_bytes) else { return ErrorCode::InvalidArgument };
 
     dev_msg!("Registering Parquet table '{}' from path '{}'", table_ref, table_path);
 
-    context.runtime.spawn(async move {
-        let result = context.inner
-            .register_parquet(&table_ref, &table_path, datafusion::prelude::ParquetReadOptions::default())
-            .await
-            .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e));
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let result = match options.to_read_options() {
+            Ok(opts) => context.inner
+                .register_parquet(&table_ref, &table_path, opts)
+                .await
+                .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::TableRegistrationFailed, e)),
+            Err(e) => Err(e)
+        };
 
         crate::invoke_callback(result, callback, user_data);
+
         dev_msg!("Finished registering Parquet table '{}' from path '{}'", table_ref, table_path);
     });
 
     ErrorCode::Ok
 }
 
-/// Deregisters a table from the `SessionContext` by name.
+/// Registers Arrow IPC file(s) as a table in the `SessionContext`.
 ///
 /// This is an async operation. The callback is invoked on completion with no result data.
 ///
 /// # Safety
 /// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
-/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with the name of a registered table
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `table_path_ptr` must be a valid null-terminated UTF-8 string
+/// - `arrow_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `ArrowReadOptions`, or null
 /// - `callback` must be valid to call from any thread
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn datafusion_context_deregister_table(
+pub unsafe extern "C" fn datafusion_context_register_arrow(
     context_ptr: *mut SessionContextWrapper,
     table_ref_ptr: *const std::ffi::c_char,
+    table_path_ptr: *const std::ffi::c_char,
+    arrow_options_bytes: crate::BytesData,
     callback: crate::Callback,
     user_data: u64
 ) -> ErrorCode {
     let context = ffi_ref!(context_ptr);
     let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+    let table_path = ffi_cstr_to_string!(table_path_ptr);
+    let Some(options) = FileReadOptions::<proto::ArrowReadOptions>::decode(&arrow_options_bytes) else { return ErrorCode::InvalidArgument };
 
-    dev_msg!("Deregistering table '{}'", table_ref);
+    dev_msg!("Registering Arrow table '{}' from path '{}'", table_ref, table_path);
 
-    let result = context.inner
-        .deregister_table(&table_ref)
-        .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let result = match options.to_read_options() {
+            Ok(opts) => context.inner
+                .register_arrow(&table_ref, &table_path, opts)
+                .await
+                .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::TableRegistrationFailed, e)),
+            Err(e) => Err(e)
+        };
+
+        crate::invoke_callback(result, callback, user_data);
+
+        dev_msg!("Finished registering Arrow table '{}' from path '{}'", table_ref, table_path);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Registers in-memory Arrow record batches as a table in the `SessionContext`.
+///
+/// The batches are imported through the Arrow C Data Interface, each one exported as a struct array
+/// whose fields are the columns described by `schema_ptr`. The data is kept in a `MemTable`.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `schema_ptr` must be a valid pointer to an `FFI_ArrowSchema` describing the batches; it stays owned by the caller
+/// - `batches_ptr` must point to `num_batches` contiguous `FFI_ArrowArray` structs, or be null if `num_batches` is 0;
+///   ownership of every array is transferred to the callee, which leaves them released even if registration fails;
+///   a negative `num_batches` is rejected before any ownership is taken
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_batches(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    schema_ptr: *const arrow_array::ffi::FFI_ArrowSchema,
+    batches_ptr: *mut arrow_array::ffi::FFI_ArrowArray,
+    num_batches: i32,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let Ok(num_batches) = usize::try_from(num_batches) else { return ErrorCode::InvalidArgument };
+
+    // Take ownership of the arrays before any other validation so that they are released on every path.
+    let Ok(ffi_arrays) = (unsafe { arrow_ffi::take_arrays(batches_ptr, num_batches) }) else { return ErrorCode::InvalidArgument };
+
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+    let ffi_schema = ffi_ref!(schema_ptr);
+
+    dev_msg!("Registering {} in-memory batches as table '{}'", num_batches, table_ref);
+
+    let batches = arrow_ffi::import_schema(ffi_schema)
+        .and_then(|schema| {
+            let batches = arrow_ffi::import_batches(ffi_arrays, ffi_schema, &schema)?;
+            Ok((schema, batches))
+        })
+        .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, e));
+
+    let result = batches
+        .and_then(|(schema, batches)| {
+            datafusion::datasource::MemTable::try_new(schema, vec![batches])
+                .and_then(|table| context.inner.register_table(&table_ref, Arc::new(table)))
+                .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+        })
         .map(|_| ());
 
     crate::invoke_callback(result, callback, user_data);
 
-    dev_msg!("Finished deregistering table '{}'", table_ref);
+    dev_msg!("Finished registering in-memory table '{}'", table_ref);
 
     ErrorCode::Ok
 }
 
-/// Executes a SQL query and returns a `DataFrame`.
+/// Registers an Arrow C stream as a streaming table in the `SessionContext`.
+///
+/// Batches are pulled from the stream on demand when the table is scanned, so the producer can generate
+/// data lazily. The stream can be read by only one query; planning a later query that scans the table
+/// fails with `ErrorCode::InvalidArgument`.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `stream_ptr` must be a valid pointer to an `FFI_ArrowArrayStream`; ownership is transferred to the callee,
+///   which leaves the caller's struct released
+/// - The stream callbacks must be valid to call from any thread until the stream is released
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_stream(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    stream_ptr: *mut arrow_array::ffi_stream::FFI_ArrowArrayStream,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+    if stream_ptr.is_null() {
+        return ErrorCode::InvalidArgument;
+    }
+
+    dev_msg!("Registering Arrow stream as table '{}'", table_ref);
+
+    let reader = unsafe { arrow_array::ffi_stream::ArrowArrayStreamReader::from_raw(stream_ptr) }
+        .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to import Arrow stream: {e}")));
+
+    let result = reader
+        .and_then(|reader| {
+            let table = streaming::ArrowStreamTable::new(&table_ref, reader);
+            context.inner.register_table(&table_ref, Arc::new(table))
+                .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+        })
+        .map(|_| ());
+
+    crate::invoke_callback(result, callback, user_data);
+
+    dev_msg!("Finished registering Arrow stream table '{}'", table_ref);
+
+    ErrorCode::Ok
+}
+
+/// Reads CSV file(s) into a `DataFrame` without registering a table.
 ///
 /// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
 ///
 /// # Safety
 /// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
-/// - `sql_ptr` must be a valid null-terminated UTF-8 string
+/// - `table_path_ptr` must be a valid null-terminated UTF-8 string
+/// - `csv_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `CsvReadOptions`, or null
 /// - `callback` must be valid to call from any thread
 /// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn datafusion_context_sql(
+pub unsafe extern "C" fn datafusion_context_read_csv(
     context_ptr: *mut SessionContextWrapper,
-    sql_ptr: *const std::ffi::c_char,
-    sql_parameters_bytes: crate::BytesData,
+    table_path_ptr: *const std::ffi::c_char,
+    csv_options_bytes: crate::BytesData,
     callback: crate::Callback,
     user_data: u64
 ) -> ErrorCode {
     let context = ffi_ref!(context_ptr);
-    let sql = ffi_cstr_to_string!(sql_ptr);
+    let table_path = ffi_cstr_to_string!(table_path_ptr);
+    let Some(options) = FileReadOptions::<proto::CsvReadOptions>::decode(&csv_options_bytes) else { return ErrorCode::InvalidArgument };
 
-    let Ok(sql_parameters_proto) = sql_parameters_bytes.as_opt_slice()
-        .map(proto::SqlParameters::decode).transpose() else { return ErrorCode::InvalidArgument };
-    let Ok(sql_parameters) = sql_parameters_proto.as_ref()
-        .map(mappers::from_proto_sql_params).transpose() else { return ErrorCode::InvalidArgument };
+    dev_msg!("Reading CSV from path '{}'", table_path);
 
-    dev_msg!("Executing SQL query: {}", sql);
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let result = match options.to_read_options() {
+            Ok(opts) => context.inner
+                .read_csv(table_path.as_str(), opts)
+                .await
+                .map(|df| Box::into_raw(Box::new(crate::DataFrameWrapper::new(Arc::clone(&context.runtime), df))))
+                .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, e)),
+            Err(e) => Err(e)
+        };
 
-    context.runtime.spawn(async move {
-        let result = context.inner
-            .sql(&sql)
-            .await
-            .and_then(|df| {
-                let df = match sql_parameters {
-                    Some(p) => df.with_param_values(p),
-                    _ => Ok(df)
-                }?;
+        crate::invoke_callback(result, callback, user_data);
 
-                Ok(Box::into_raw(Box::new(crate::DataFrameWrapper::new(Arc::clone(&context.runtime), df))))
-            })
-            .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e));
+        dev_msg!("Finished reading CSV from path '{}'", table_path);
+    });
 
-        dev_msg!("Finished executing SQL query: {}, dataframe ptr: {:p}", sql, result.as_ref().ok().map_or(std::ptr::null(), |ptr| *ptr));
+    ErrorCode::Ok
+}
+
+/// Reads JSON file(s) into a `DataFrame` without registering a table.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_path_ptr` must be a valid null-terminated UTF-8 string
+/// - `json_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `JsonReadOptions`, or null
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_read_json(
+    context_ptr: *mut SessionContextWrapper,
+    table_path_ptr: *const std::ffi::c_char,
+    json_options_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_path = ffi_cstr_to_string!(table_path_ptr);
+    let Some(options) = FileReadOptions::<proto::JsonReadOptions>::decode(&json_options_bytes) else { return ErrorCode::InvalidArgument };
+
+    dev_msg!("Reading JSON from path '{}'", table_path);
+
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let result = match options.to_read_options() {
+            Ok(opts) => context.inner
+                .read_json(table_path.as_str(), opts)
+                .await
+                .map(|df| Box::into_raw(Box::new(crate::DataFrameWrapper::new(Arc::clone(&context.runtime), df))))
+                .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, e)),
+            Err(e) => Err(e)
+        };
+
+        crate::invoke_callback(result, callback, user_data);
+
+        dev_msg!("Finished reading JSON from path '{}'", table_path);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Reads Parquet file(s) into a `DataFrame` without registering a table.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_path_ptr` must be a valid null-terminated UTF-8 string
+/// - `parquet_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `ParquetReadOptions`, or null
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_read_parquet(
+    context_ptr: *mut SessionContextWrapper,
+    table_path_ptr: *const std::ffi::c_char,
+    parquet_options_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_path = ffi_cstr_to_string!(table_path_ptr);
+    let Some(options) = FileReadOptions::<proto::ParquetReadOptions>::decode(&parquet_options_bytes) else { return ErrorCode::InvalidArgument };
+
+    dev_msg!("Reading Parquet from path '{}'", table_path);
+
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let result = match options.to_read_options() {
+            Ok(opts) => context.inner
+                .read_parquet(table_path.as_str(), opts)
+                .await
+                .map(|df| Box::into_raw(Box::new(crate::DataFrameWrapper::new(Arc::clone(&context.runtime), df))))
+                .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, e)),
+            Err(e) => Err(e)
+        };
+
+        crate::invoke_callback(result, callback, user_data);
+
+        dev_msg!("Finished reading Parquet from path '{}'", table_path);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Reads Arrow IPC file(s) into a `DataFrame` without registering a table.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_path_ptr` must be a valid null-terminated UTF-8 string
+/// - `arrow_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `ArrowReadOptions`, or null
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_read_arrow(
+    context_ptr: *mut SessionContextWrapper,
+    table_path_ptr: *const std::ffi::c_char,
+    arrow_options_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_path = ffi_cstr_to_string!(table_path_ptr);
+    let Some(options) = FileReadOptions::<proto::ArrowReadOptions>::decode(&arrow_options_bytes) else { return ErrorCode::InvalidArgument };
+
+    dev_msg!("Reading Arrow from path '{}'", table_path);
+
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let result = match options.to_read_options() {
+            Ok(opts) => context.inner
+                .read_arrow(table_path.as_str(), opts)
+                .await
+                .map(|df| Box::into_raw(Box::new(crate::DataFrameWrapper::new(Arc::clone(&context.runtime), df))))
+                .map_err(|e| ErrorInfo::from_datafusion(ErrorCode::DataFrameError, e)),
+            Err(e) => Err(e)
+        };
 
         crate::invoke_callback(result, callback, user_data);
+
+        dev_msg!("Finished reading Arrow from path '{}'", table_path);
     });
 
     ErrorCode::Ok
 }
+
+/// Registers a `DataFrame` as a named view in the `SessionContext`.
+///
+/// The view keeps the logical plan of the `DataFrame`, so it is re-evaluated every time it is queried.
+/// The `DataFrame` itself is not consumed and can still be used by the caller.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `df_ptr` must be a valid `DataFrame` pointer
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_dataframe_view(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    df_ptr: *mut crate::DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    dev_msg!("Registering DataFrame {:p} as view '{}'", df_ptr, table_ref);
+
+    let view = df_wrapper.inner().clone().into_view();
+    let result = context.inner
+        .register_table(&table_ref, view)
+        .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+        .map(|_| ());
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Executes a `DataFrame` and registers the materialized result as an in-memory table in the `SessionContext`.
+///
+/// The `DataFrame` itself is not consumed and can still be used by the caller.
+///
+/// This is an async operation. The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `df_ptr` must be a valid `DataFrame` pointer that is not destroyed before the callback is invoked
+/// - `callback` must be valid to call from any thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_dataframe_table(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    df_ptr: *mut crate::DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    dev_msg!("Materializing DataFrame {:p} as table '{}'", df_ptr, table_ref);
+
+    let df = df_wrapper.inner().clone();
+
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let schema = Arc::clone(df.schema().inner());
+        let result = match df.collect_partitioned().await {
+            Ok(partitions) => datafusion::datasource::MemTable::try_new(schema, partitions)
+                .and_then(|table| context.inner.register_table(&table_ref, Arc::new(table)))
+                .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+                .map(|_| ()),
+            Err(e) => Err(ErrorInfo::from_datafusion(ErrorCode::DataFrameError, e))
+        };
+
+        crate::invoke_callback(result, callback, user_data);
+        dev_msg!("Finished materializing DataFrame as table '{}'", table_ref);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Deregisters a table from the `SessionContext` by name.
+///
+/// This is an async operation. The callback is invoked on completion with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string with the name of a registered table
+/// - `callback` must be valid to call from any thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_deregister_table(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+
+    dev_msg!("Deregistering table '{}'", table_ref);
+
+    let result = context.inner
+        .deregister_table(&table_ref)
+        .map_err(|e| ErrorInfo::new(ErrorCode::TableRegistrationFailed, e))
+        .map(|_| ());
+
+    crate::invoke_callback(result, callback, user_data);
+
+    dev_msg!("Finished deregistering table '{}'", table_ref);
+
+    ErrorCode::Ok
+}
+
+/// Executes a SQL query and returns a `DataFrame`.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `sql_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+///
+/// # Parameters
+/// - `timeout_millis`: Deadline for the operation in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_sql(
+    context_ptr: *mut SessionContextWrapper,
+    sql_ptr: *const std::ffi::c_char,
+    sql_parameters_bytes: crate::BytesData,
+    timeout_millis: u64,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    unsafe {
+        datafusion_context_sql_with_options(
+            context_ptr,
+            sql_ptr,
+            sql_parameters_bytes,
+            crate::BytesData::empty(),
+            timeout_millis,
+            callback,
+            user_data
+        )
+    }
+}
+
+/// Executes a SQL query restricted by `SqlOptions` and returns a `DataFrame`.
+///
+/// Statements rejected by the options fail with `ErrorCode::StatementNotAllowed` without being executed.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `sql_ptr` must be a valid null-terminated UTF-8 string
+/// - `sql_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `SqlOptions`, or empty to allow all statements
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+///
+/// # Parameters
+/// - `timeout_millis`: Deadline for the operation in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_sql_with_options(
+    context_ptr: *mut SessionContextWrapper,
+    sql_ptr: *const std::ffi::c_char,
+    sql_parameters_bytes: crate::BytesData,
+    sql_options_bytes: crate::BytesData,
+    timeout_millis: u64,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let sql = ffi_cstr_to_string!(sql_ptr);
+
+    let Ok(sql_parameters_proto) = sql_parameters_bytes.as_opt_slice()
+        .map(proto::SqlParameters::decode).transpose() else { return ErrorCode::InvalidArgument };
+    let Ok(sql_parameters) = sql_parameters_proto.as_ref()
+        .map(mappers::from_proto_sql_params).transpose() else { return ErrorCode::InvalidArgument };
+    let Ok((sql_options, dialect)) = decode_sql_options(&sql_options_bytes) else { return ErrorCode::InvalidArgument };
+
+    dev_msg!("Executing SQL query: {}", sql);
+
+    crate::spawn_operation_with_timeout(&context.runtime, callback, user_data, timeout_millis, async move {
+        let dialect = match dialect.as_deref().map(parse_dialect).transpose() {
+            Ok(dialect) => dialect,
+            Err(error) => {
+                crate::invoke_callback_error(&error, callback, user_data);
+                return;
+            }
+        };
+
+        let result = context
+            .run_sql(&sql, sql_options.as_ref(), dialect)
+            .await
+            .and_then(|df| {
+                let df = match sql_parameters {
+                    Some(p) => df.with_param_values(p),
+                    _ => Ok(df)
+                }.map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e))?;
+
+                Ok(Box::into_raw(Box::new(crate::DataFrameWrapper::new(Arc::clone(&context.runtime), df))))
+            });
+
+        dev_msg!("Finished executing SQL query: {}, dataframe ptr: {:p}", sql, result.as_ref().ok().map_or(std::ptr::null(), |ptr| *ptr));
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Plans a SQL query without executing it and returns the inferred types of its placeholders.
+///
+/// Statements with side effects, such as DDL, are planned but not executed. Statements rejected by the options
+/// fail with `ErrorCode::StatementNotAllowed`, as they would when executed with `datafusion_context_sql_with_options`.
+///
+/// This is an async operation. The callback is invoked on completion with a protobuf-encoded `SqlParameterTypes` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `sql_ptr` must be a valid null-terminated UTF-8 string
+/// - `sql_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `SqlOptions`, or empty to allow all statements
+/// - `callback` must be valid to call from any thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_sql_parameter_types(
+    context_ptr: *mut SessionContextWrapper,
+    sql_ptr: *const std::ffi::c_char,
+    sql_options_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let sql = ffi_cstr_to_string!(sql_ptr);
+
+    let Ok((sql_options, dialect)) = decode_sql_options(&sql_options_bytes) else { return ErrorCode::InvalidArgument };
+
+    dev_msg!("Inferring parameter types of SQL query: {}", sql);
+
+    crate::spawn_operation(&context.runtime, callback, user_data, async move {
+        let result = context
+            .plan_sql_with_options(&sql, sql_options.as_ref(), dialect.as_deref())
+            .await
+            .and_then(|plan| plan.get_parameter_types()
+                .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e)))
+            .and_then(|types| mappers::to_proto_sql_parameter_types(types)
+                .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e)));
+
+        crate::invoke_callback_message(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Sets the SQL dialect used to parse queries of the `SessionContext`.
+///
+/// Equivalent to setting `datafusion.sql_parser.dialect`. Unknown dialect names fail with `ErrorCode::InvalidArgument`.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `dialect_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_set_dialect(
+    context_ptr: *mut SessionContextWrapper,
+    dialect_ptr: *const std::ffi::c_char,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let dialect_name = ffi_cstr_to_string!(dialect_ptr);
+
+    dev_msg!("Setting SQL dialect to '{}'", dialect_name);
+
+    let result = parse_dialect(&dialect_name)
+        .map(|dialect| {
+            context.inner.state_ref().write().config_mut().options_mut().sql_parser.dialect = dialect;
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+pub(crate) fn parse_dialect(name: &str) -> Result<Dialect, ErrorInfo> {
+    name.parse::<Dialect>()
+        .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, e))
+}
+
+/// Decodes optional `SqlOptions` into the statement restrictions and the name of the per-query dialect,
+/// or `None` for both if the bytes are empty.
+pub(crate) fn decode_sql_options(sql_options_bytes: &crate::BytesData) -> Result<(Option<SQLOptions>, Option<String>), prost::DecodeError> {
+    let sql_options_proto = sql_options_bytes.as_opt_slice()
+        .map(proto::SqlOptions::decode).transpose()?;
+    let sql_options = sql_options_proto.as_ref().map(mappers::from_proto_sql_options);
+
+    Ok((sql_options, sql_options_proto.and_then(|o| o.dialect)))
+}
+
+/// Creates a new in-memory catalog in the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_create_catalog(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+
+    dev_msg!("Creating catalog '{}'", catalog_name);
+
+    let result = if context.inner.catalog(&catalog_name).is_some() {
+        Err(ErrorInfo::new(ErrorCode::CatalogError, format!("Catalog '{catalog_name}' already exists")))
+    } else {
+        let catalog = Arc::new(datafusion::catalog::MemoryCatalogProvider::new());
+        context.inner.register_catalog(&catalog_name, catalog);
+        Ok(())
+    };
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Drops a catalog from the `SessionContext`.
+///
+/// The default catalog cannot be dropped. A catalog that still contains schemas is only dropped when `cascade` is true.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_drop_catalog(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    cascade: bool,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+
+    dev_msg!("Dropping catalog '{}'", catalog_name);
+
+    let result = drop_catalog(context, &catalog_name, cascade);
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+fn drop_catalog(context: &SessionContextWrapper, catalog_name: &str, cascade: bool) -> Result<(), ErrorInfo> {
+    let state = context.inner.state();
+    if state.config().options().catalog.default_catalog == catalog_name {
+        return Err(ErrorInfo::new(ErrorCode::CatalogError, format!("Default catalog '{catalog_name}' cannot be dropped")));
+    }
+
+    let catalog = context.catalog(catalog_name)?;
+    if !cascade && !catalog.schema_names().is_empty() {
+        return Err(ErrorInfo::new(ErrorCode::CatalogError, format!("Catalog '{catalog_name}' is not empty")));
+    }
+
+    let catalog_list = state.catalog_list();
+    let Some(memory_catalog_list) = catalog_list.as_any().downcast_ref::<datafusion::catalog::MemoryCatalogProviderList>() else {
+        return Err(ErrorInfo::new(ErrorCode::CatalogError, "Catalog list does not support dropping catalogs"));
+    };
+
+    memory_catalog_list.catalogs.remove(catalog_name);
+
+    Ok(())
+}
+
+/// Creates a new in-memory schema in an existing catalog.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `schema_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_create_schema(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    schema_name_ptr: *const std::ffi::c_char,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+    let schema_name = ffi_cstr_to_string!(schema_name_ptr);
+
+    dev_msg!("Creating schema '{}.{}'", catalog_name, schema_name);
+
+    let result = context.catalog(&catalog_name)
+        .and_then(|catalog| {
+            if catalog.schema(&schema_name).is_some() {
+                return Err(ErrorInfo::new(ErrorCode::CatalogError, format!("Schema '{catalog_name}.{schema_name}' already exists")));
+            }
+
+            let schema = Arc::new(datafusion::catalog::MemorySchemaProvider::new());
+            catalog.register_schema(&schema_name, schema)
+                .map_err(|e| ErrorInfo::new(ErrorCode::CatalogError, e))
+                .map(|_| ())
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Drops a schema from a catalog.
+///
+/// A schema that still contains tables is only dropped when `cascade` is true.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `schema_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_drop_schema(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    schema_name_ptr: *const std::ffi::c_char,
+    cascade: bool,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+    let schema_name = ffi_cstr_to_string!(schema_name_ptr);
+
+    dev_msg!("Dropping schema '{}.{}'", catalog_name, schema_name);
+
+    let result = context.catalog(&catalog_name)
+        .and_then(|catalog| {
+            catalog.deregister_schema(&schema_name, cascade)
+                .map_err(|e| ErrorInfo::new(ErrorCode::CatalogError, e))?
+                .ok_or_else(|| ErrorInfo::new(ErrorCode::CatalogError, format!("Schema '{catalog_name}.{schema_name}' does not exist")))
+                .map(|_| ())
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Lists the catalogs registered in the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a protobuf-encoded `CatalogList` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_list_catalogs(
+    context_ptr: *mut SessionContextWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+
+    let mut catalogs = context.inner.catalog_names();
+    catalogs.sort();
+
+    crate::invoke_callback_message(Ok(proto::CatalogList { catalogs }), callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Lists the schemas registered in a catalog.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a protobuf-encoded `SchemaList` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_list_schemas(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+
+    let result = context.catalog(&catalog_name)
+        .map(|catalog| {
+            let mut schemas = catalog.schema_names();
+            schemas.sort();
+
+            proto::SchemaList { catalog: catalog_name, schemas }
+        });
+
+    crate::invoke_callback_message(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Lists the tables registered in a schema.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a protobuf-encoded `TableList` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `catalog_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `schema_name_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_list_tables(
+    context_ptr: *mut SessionContextWrapper,
+    catalog_name_ptr: *const std::ffi::c_char,
+    schema_name_ptr: *const std::ffi::c_char,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let catalog_name = ffi_cstr_to_string!(catalog_name_ptr);
+    let schema_name = ffi_cstr_to_string!(schema_name_ptr);
+
+    let result = context.schema(&catalog_name, &schema_name)
+        .map(|schema| {
+            let mut tables = schema.table_names();
+            tables.sort();
+
+            proto::TableList { catalog: catalog_name, schema: schema_name, tables }
+        });
+
+    crate::invoke_callback_message(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Checks whether a table exists in the `SessionContext`.
+///
+/// The table reference may be bare (`table`), partial (`schema.table`) or full (`catalog.schema.table`).
+///
+/// This is a synchronous operation. The callback is invoked immediately with the result as a `bool`.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `table_ref_ptr` must be a valid null-terminated UTF-8 string
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_table_exists(
+    context_ptr: *mut SessionContextWrapper,
+    table_ref_ptr: *const std::ffi::c_char,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let table_ref = ffi_cstr_to_string!(table_ref_ptr);
+
+    let result = context.inner
+        .table_exist(table_ref.as_str())
+        .map_err(|e| ErrorInfo::new(ErrorCode::CatalogError, e));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Sets a configuration option on a live `SessionContext`.
+///
+/// The key is validated against `ConfigOptions`, including registered extensions. The new value applies to
+/// queries planned after this call.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `key_ptr` and `value_ptr` must be valid null-terminated UTF-8 strings
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_set_option(
+    context_ptr: *mut SessionContextWrapper,
+    key_ptr: *const std::ffi::c_char,
+    value_ptr: *const std::ffi::c_char,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let key = ffi_cstr_to_string!(key_ptr);
+    let value = ffi_cstr_to_string!(value_ptr);
+
+    dev_msg!("Setting option '{}' to '{}'", key, value);
+
+    let result = context.inner
+        .state_ref()
+        .write()
+        .config_mut()
+        .options_mut()
+        .set(&key, &value)
+        .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, e));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Gets the effective configuration of the `SessionContext`.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a protobuf-encoded `ConfigEntryList` as bytes.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_get_options(
+    context_ptr: *mut SessionContextWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+
+    let mut entries = context.inner
+        .state()
+        .config()
+        .options()
+        .entries()
+        .into_iter()
+        .map(|e| proto::ConfigEntry { key: e.key, value: e.value, description: e.description.to_string() })
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+
+    crate::invoke_callback_message(Ok(proto::ConfigEntryList { entries }), callback, user_data);
+
+    ErrorCode::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use super::*;
+    use crate::test_util::{boolean, bytes, call, unit};
+
+    fn new_context() -> SessionContextWrapper {
+        let runtime = Arc::new(tokio::runtime::Builder::new_current_thread().build().unwrap());
+        SessionContextWrapper::new(runtime, datafusion::prelude::SessionConfig::new(), Arc::default())
+    }
+
+    fn c(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    #[test]
+    fn catalog_management_round_trip() {
+        let mut context = new_context();
+        let ctx = &raw mut context;
+        let (sales, eu) = (c("sales"), c("eu"));
+
+        let list_catalogs = || {
+            let catalogs = call(bytes, |cb, ud| unsafe { datafusion_context_list_catalogs(ctx, cb, ud) }).unwrap();
+            proto::CatalogList::decode(catalogs.as_slice()).unwrap().catalogs
+        };
+
+        call(unit, |cb, ud| unsafe { datafusion_context_create_catalog(ctx, sales.as_ptr(), cb, ud) }).unwrap();
+        call(unit, |cb, ud| unsafe { datafusion_context_create_schema(ctx, sales.as_ptr(), eu.as_ptr(), cb, ud) }).unwrap();
+        assert_eq!(list_catalogs(), vec!["datafusion", "sales"]);
+
+        let schemas = call(bytes, |cb, ud| unsafe { datafusion_context_list_schemas(ctx, sales.as_ptr(), cb, ud) }).unwrap();
+        assert_eq!(proto::SchemaList::decode(schemas.as_slice()).unwrap().schemas, vec!["eu"]);
+
+        let schema = Arc::new(datafusion::arrow::datatypes::Schema::empty());
+        let table = datafusion::datasource::MemTable::try_new(schema, vec![Vec::new()]).unwrap();
+        context.inner.register_table("sales.eu.items", Arc::new(table)).unwrap();
+
+        let tables = call(bytes, |cb, ud| unsafe { datafusion_context_list_tables(ctx, sales.as_ptr(), eu.as_ptr(), cb, ud) }).unwrap();
+        assert_eq!(proto::TableList::decode(tables.as_slice()).unwrap().tables, vec!["items"]);
+        let exists = call(boolean, |cb, ud| unsafe { datafusion_context_table_exists(ctx, c("sales.eu.items").as_ptr(), cb, ud) }).unwrap();
+        assert!(exists);
+
+        let error = call(unit, |cb, ud| unsafe { datafusion_context_drop_catalog(ctx, sales.as_ptr(), false, cb, ud) }).unwrap_err();
+        assert_eq!(error.code(), ErrorCode::CatalogError);
+        let error = call(unit, |cb, ud| unsafe { datafusion_context_drop_schema(ctx, sales.as_ptr(), eu.as_ptr(), false, cb, ud) }).unwrap_err();
+        assert_eq!(error.code(), ErrorCode::CatalogError);
+
+        call(unit, |cb, ud| unsafe { datafusion_context_drop_schema(ctx, sales.as_ptr(), eu.as_ptr(), true, cb, ud) }).unwrap();
+        call(unit, |cb, ud| unsafe { datafusion_context_drop_catalog(ctx, sales.as_ptr(), false, cb, ud) }).unwrap();
+        assert_eq!(list_catalogs(), vec!["datafusion"]);
+    }
+
+    #[test]
+    fn catalog_management_rejects_duplicates_and_default_catalog() {
+        let mut context = new_context();
+        let ctx = &raw mut context;
+        let (sales, datafusion) = (c("sales"), c("datafusion"));
+
+        call(unit, |cb, ud| unsafe { datafusion_context_create_catalog(ctx, sales.as_ptr(), cb, ud) }).unwrap();
+
+        let error = call(unit, |cb, ud| unsafe { datafusion_context_create_catalog(ctx, sales.as_ptr(), cb, ud) }).unwrap_err();
+        assert_eq!(error.message(), "Catalog 'sales' already exists");
+        let error = call(unit, |cb, ud| unsafe { datafusion_context_drop_catalog(ctx, datafusion.as_ptr(), true, cb, ud) }).unwrap_err();
+        assert_eq!(error.message(), "Default catalog 'datafusion' cannot be dropped");
+        let error = call(unit, |cb, ud| unsafe { datafusion_context_list_schemas(ctx, c("missing").as_ptr(), cb, ud) }).unwrap_err();
+        assert_eq!(error.code(), ErrorCode::CatalogError);
+    }
+}
@@ -15,6 +15,63 @@ impl DataFrameWrapper {
             inner,
         }
     }
+
+    pub(crate) fn inner(&self) -> &datafusion::prelude::DataFrame {
+        &self.inner
+    }
+
+    /// Decodes an expression, resolving functions registered in the `DataFrame`'s session.
+    fn parse_expr(&self, node: &datafusion_proto::protobuf::LogicalExprNode) -> Result<datafusion::prelude::Expr, crate::ErrorInfo> {
+        mappers::from_proto_expr(node, &self.inner.task_ctx())
+            .map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::InvalidArgument, e))
+    }
+
+    fn parse_exprs(&self, nodes: &[datafusion_proto::protobuf::LogicalExprNode]) -> Result<Vec<datafusion::prelude::Expr>, crate::ErrorInfo> {
+        mappers::from_proto_exprs(nodes, &self.inner.task_ctx())
+            .map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::InvalidArgument, e))
+    }
+
+    /// Checks that `other` was created by the same session, as required to combine two `DataFrame`s.
+    fn ensure_same_session(&self, other: &DataFrameWrapper) -> Result<(), crate::ErrorInfo> {
+        let session_id = self.inner.task_ctx().session_id();
+        let other_session_id = other.inner.task_ctx().session_id();
+
+        if session_id == other_session_id {
+            Ok(())
+        } else {
+            Err(crate::ErrorInfo::new(crate::ErrorCode::InvalidArgument, format!(
+                "DataFrames belong to different sessions ('{session_id}' and '{other_session_id}')")))
+        }
+    }
+
+    /// Checks that `other` has as many columns as this `DataFrame`, as required by set operations.
+    fn ensure_same_column_count(&self, other: &DataFrameWrapper, operation: &str) -> Result<(), crate::ErrorInfo> {
+        let fields = self.inner.schema().fields();
+        let other_fields = other.inner.schema().fields();
+
+        if fields.len() == other_fields.len() {
+            return Ok(());
+        }
+
+        let names = |fields: &datafusion::arrow::datatypes::Fields| fields.iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Err(crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, format!(
+            "{operation} requires the same number of columns, but left has {} ({}) and right has {} ({})",
+            fields.len(), names(fields), other_fields.len(), names(other_fields))))
+    }
+
+    /// Applies `transform` to a copy of the `DataFrame` and wraps the result for the caller to own.
+    fn transform<F>(&self, transform: F) -> Result<*mut DataFrameWrapper, crate::ErrorInfo>
+    where
+        F: FnOnce(datafusion::prelude::DataFrame) -> datafusion::error::Result<datafusion::prelude::DataFrame>
+    {
+        transform(self.inner.clone())
+            .map(|df| Box::into_raw(Box::new(DataFrameWrapper::new(Arc::clone(&self.runtime), df))))
+            .map_err(|e| crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, e))
+    }
 }
 
 /// Destroys a `DataFrame` and frees its resources.
@@ -48,12 +105,12 @@ pub unsafe extern "C" fn datafusion_dataframe_count(
 
     dev_msg!("Executing count on DataFrame: {:p}", df_ptr);
 
-    df_wrapper.runtime.spawn(async move {
+    crate::spawn_operation(&df_wrapper.runtime, callback, user_data, async move {
         let df = df_wrapper.inner.clone();
         let result = df
             .count()
             .await
-            .map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, e))
+            .map_err(|e| crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, e))
             .map(|s| s as u64);
 
         crate::invoke_callback(result, callback, user_data);
@@ -83,14 +140,14 @@ pub unsafe extern "C" fn datafusion_dataframe_show(
 
     dev_msg!("Executing show on DataFrame: {:p}", df_ptr);
 
-    df_wrapper.runtime.spawn(async move {
+    crate::spawn_operation(&df_wrapper.runtime, callback, user_data, async move {
         let df = df_wrapper.inner.clone();
         let result = if limit > 0 {
             #[allow(clippy::cast_possible_truncation)]
             df.show_limit(limit as usize).await
         } else {
             df.show().await
-        }.map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, e));
+        }.map_err(|e| crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, e));
 
         crate::invoke_callback(result, callback, user_data);
     });
@@ -115,7 +172,7 @@ pub unsafe extern "C" fn datafusion_dataframe_to_string(
 
     dev_msg!("Executing to_string on DataFrame: {:p}", df_ptr);
 
-    df_wrapper.runtime.spawn(async move {
+    crate::spawn_operation(&df_wrapper.runtime, callback, user_data, async move {
         let df = df_wrapper.inner.clone();
         let result = df
             .to_string()
@@ -127,7 +184,7 @@ pub unsafe extern "C" fn datafusion_dataframe_to_string(
                 crate::invoke_callback(Ok(data), callback, user_data);
             }
             Err(err) => {
-                let err_info = crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, err);
+                let err_info = crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, err);
                 crate::invoke_callback(Err::<crate::BytesData, _>(err_info), callback, user_data);
             }
         }
@@ -176,15 +233,20 @@ pub struct CollectedData {
 /// # Safety
 /// - `df_ptr` must be a valid pointer returned by other public functions
 /// - `callback` must be valid to call from any thread
+///
+/// # Parameters
+/// - `timeout_millis`: Deadline for the operation in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn datafusion_dataframe_collect(
     df_ptr: *mut DataFrameWrapper,
+    timeout_millis: u64,
     callback: crate::Callback,
     user_data: u64
 ) -> crate::ErrorCode {
     let df_wrapper = ffi_ref!(df_ptr);
 
-    df_wrapper.runtime.spawn(async move {
+    crate::spawn_operation_with_timeout(&df_wrapper.runtime, callback, user_data, timeout_millis, async move {
         let df = df_wrapper.inner.clone();
 
         let ffi_schema = match convert_schema_to_ffi(&df) {
@@ -195,10 +257,13 @@ pub unsafe extern "C" fn datafusion_dataframe_collect(
             }
         };
 
-        let Ok(batches) = df.collect().await else {
-            let error = crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, "Failed to collect record batches");
-            crate::invoke_callback_error(&error, callback, user_data);
-            return;
+        let batches = match df.collect().await {
+            Ok(batches) => batches,
+            Err(err) => {
+                let error = crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, err);
+                crate::invoke_callback_error(&error, callback, user_data);
+                return;
+            }
         };
         let ffi_batches = batches.iter().map(convert_batch_to_ffi).collect::<Vec<_>>();
 
@@ -250,7 +315,7 @@ pub unsafe extern "C" fn datafusion_dataframe_execute_stream(
 
     dev_msg!("Executing dataframe stream on DataFrame: {:p}", df_ptr);
 
-    df_wrapper.runtime.spawn(async move {
+    crate::spawn_operation(&df_wrapper.runtime, callback, user_data, async move {
         let df = df_wrapper.inner.clone();
 
         let ffi_schema = match convert_schema_to_ffi(&df) {
@@ -261,10 +326,13 @@ pub unsafe extern "C" fn datafusion_dataframe_execute_stream(
             }
         };
 
-        let Ok(stream) = df.execute_stream().await else {
-            let error = crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, "Failed to execute dataframe stream");
-            crate::invoke_callback_error(&error, callback, user_data);
-            return;
+        let stream = match df.execute_stream().await {
+            Ok(stream) => stream,
+            Err(err) => {
+                let error = crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, err);
+                crate::invoke_callback_error(&error, callback, user_data);
+                return;
+            }
         };
 
         let stream_w = Box::into_raw(Box::new(DataFrameStreamWrapper {
@@ -312,9 +380,14 @@ pub unsafe extern "C" fn datafusion_dataframe_stream_destroy(
 /// # Safety
 /// - `stream_ptr` must be a valid pointer returned by `datafusion_dataframe_execute_stream`
 /// - `callback` must be valid to call from any thread
+///
+/// # Parameters
+/// - `timeout_millis`: Deadline for the operation in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn datafusion_dataframe_stream_next(
     stream_ptr: *mut DataFrameStreamWrapper,
+    timeout_millis: u64,
     callback: crate::Callback,
     user_data: u64
 ) -> crate::ErrorCode {
@@ -322,7 +395,7 @@ pub unsafe extern "C" fn datafusion_dataframe_stream_next(
     
     let runtime = Arc::clone(&stream_wrapper.runtime);
 
-    runtime.spawn(async move {
+    crate::spawn_operation_with_timeout(&runtime, callback, user_data, timeout_millis, async move {
         match stream_wrapper.stream.next().await {
             Some(result) => match result {
                 Ok(batch) => {
@@ -330,7 +403,7 @@ pub unsafe extern "C" fn datafusion_dataframe_stream_next(
                     crate::invoke_callback_success(ffi_batch, callback, user_data);
                 },
                 Err(err) => {
-                    let error = crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, err);
+                    let error = crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, err);
                     crate::invoke_callback_error(&error, callback, user_data);
                 }
             },
@@ -349,12 +422,17 @@ pub unsafe extern "C" fn datafusion_dataframe_stream_next(
 /// - `df_ptr` must be a valid pointer returned by other public functions
 /// - `path_ptr` must be a valid null-terminated UTF-8 string
 /// - `callback` must be valid to call from any thread
+///
+/// # Parameters
+/// - `timeout_millis`: Deadline for the operation in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn datafusion_dataframe_write_csv(
     df_ptr: *mut DataFrameWrapper,
     path_ptr: *const std::ffi::c_char,
     dataframe_write_options_bytes: crate::BytesData,
     csv_write_options_bytes: crate::BytesData,
+    timeout_millis: u64,
     callback: crate::Callback,
     user_data: u64
 ) -> crate::ErrorCode {
@@ -371,12 +449,12 @@ pub unsafe extern "C" fn datafusion_dataframe_write_csv(
         )
         .transpose() else { return crate::ErrorCode::InvalidArgument };
 
-    df_wrapper.runtime.spawn(async move {
+    crate::spawn_operation_with_timeout(&df_wrapper.runtime, callback, user_data, timeout_millis, async move {
         let df = df_wrapper.inner.clone();
         let result = df
             .write_csv(&path, dataframe_write_options, csv_write_options)
             .await
-            .map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, e));
+            .map_err(|e| crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, e));
 
         crate::invoke_callback(result, callback, user_data);
     });
@@ -393,12 +471,17 @@ pub unsafe extern "C" fn datafusion_dataframe_write_csv(
 /// - `path_ptr` must be a valid null-terminated UTF-8 string
 /// - `json_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `JsonOptions`, or null
 /// - `callback` must be valid to call from any thread
+///
+/// # Parameters
+/// - `timeout_millis`: Deadline for the operation in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn datafusion_dataframe_write_json(
     df_ptr: *mut DataFrameWrapper,
     path_ptr: *const std::ffi::c_char,
     dataframe_write_options_bytes: crate::BytesData,
     json_write_options_bytes: crate::BytesData,
+    timeout_millis: u64,
     callback: crate::Callback,
     user_data: u64
 ) -> crate::ErrorCode {
@@ -417,12 +500,12 @@ pub unsafe extern "C" fn datafusion_dataframe_write_json(
 
     dev_msg!("Executing write_json on DataFrame: {:p} to path: {}", df_ptr, path);
 
-    df_wrapper.runtime.spawn(async move {
+    crate::spawn_operation_with_timeout(&df_wrapper.runtime, callback, user_data, timeout_millis, async move {
         let df = df_wrapper.inner.clone();
         let result = df
             .write_json(&path, dataframe_write_options, json_write_options)
             .await
-            .map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, e));
+            .map_err(|e| crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, e));
 
         dev_msg!("Finished executing write_json");
 
@@ -439,25 +522,48 @@ pub unsafe extern "C" fn datafusion_dataframe_write_json(
 /// # Safety
 /// - `df_ptr` must be a valid pointer returned by other public functions
 /// - `path_ptr` must be a valid null-terminated UTF-8 string
+/// - `dataframe_write_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `DataFrameWriteOptions`, or null
+/// - `parquet_write_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `ParquetWriteOptions`, or null to use the session's Parquet settings
 /// - `callback` must be valid to call from any thread
+///
+/// # Parameters
+/// - `timeout_millis`: Deadline for the operation in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn datafusion_dataframe_write_parquet(
     df_ptr: *mut DataFrameWrapper,
     path_ptr: *const std::ffi::c_char,
+    dataframe_write_options_bytes: crate::BytesData,
+    parquet_write_options_bytes: crate::BytesData,
+    timeout_millis: u64,
     callback: crate::Callback,
     user_data: u64
 ) -> crate::ErrorCode {
     let df_wrapper = ffi_ref!(df_ptr);
     let path = ffi_cstr_to_string!(path_ptr);
 
+    let Ok(dataframe_write_options_proto) = dataframe_write_options_bytes.as_opt_slice()
+        .map(proto::DataFrameWriteOptions::decode).transpose() else { return crate::ErrorCode::InvalidArgument };
+    let Ok(dataframe_write_options) = mappers::from_proto_dataframe_write_options(dataframe_write_options_proto.as_ref()) else { return crate::ErrorCode::InvalidArgument };
+
+    let Ok(parquet_write_options_proto) = parquet_write_options_bytes.as_opt_slice()
+        .map(proto::ParquetWriteOptions::decode).transpose() else { return crate::ErrorCode::InvalidArgument };
+    let Ok(parquet_write_options) = parquet_write_options_proto
+        .map(|pbo| {
+            let session_options = df_wrapper.inner.task_ctx().session_config().options().clone();
+            let defaults = datafusion::common::config::TableOptions::default_from_session_config(&session_options).parquet;
+            mappers::from_proto_parquet_write_options(&pbo, defaults)
+        })
+        .transpose() else { return crate::ErrorCode::InvalidArgument };
+
     dev_msg!("Executing write_parquet on DataFrame: {:p} to path: {}", df_ptr, path);
 
-    df_wrapper.runtime.spawn(async move {
+    crate::spawn_operation_with_timeout(&df_wrapper.runtime, callback, user_data, timeout_millis, async move {
         let df = df_wrapper.inner.clone();
         let result = df
-            .write_parquet(&path, datafusion::dataframe::DataFrameWriteOptions::default(), None)
+            .write_parquet(&path, dataframe_write_options, parquet_write_options)
             .await
-            .map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, e));
+            .map_err(|e| crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, e));
 
         dev_msg!("Finished executing write_parquet");
 
@@ -483,4 +589,768 @@ fn convert_batch_to_ffi(batch: &arrow_array::RecordBatch) -> arrow_array::ffi::F
     let st = arrow_array::StructArray::new(fields, arrays, None);
 
     arrow_array::ffi::FFI_ArrowArray::new(&st.to_data())
-}
\ No newline at end of file
+}
+
+/// Projects the `DataFrame` onto a list of expressions.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `exprs_bytes` must be a valid `BytesData` containing a protobuf-encoded `LogicalExprList`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_select(
+    df_ptr: *mut DataFrameWrapper,
+    exprs_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let Some(Ok(exprs_proto)) = exprs_bytes.as_opt_slice()
+        .map(datafusion_proto::protobuf::LogicalExprList::decode) else { return crate::ErrorCode::InvalidArgument };
+
+    let result = df_wrapper.parse_exprs(&exprs_proto.expr)
+        .and_then(|exprs| df_wrapper.transform(|df| df.select(exprs)));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Filters the `DataFrame` rows by a boolean predicate expression.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `predicate_bytes` must be a valid `BytesData` containing a protobuf-encoded `LogicalExprNode`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_filter(
+    df_ptr: *mut DataFrameWrapper,
+    predicate_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let Some(Ok(predicate_proto)) = predicate_bytes.as_opt_slice()
+        .map(datafusion_proto::protobuf::LogicalExprNode::decode) else { return crate::ErrorCode::InvalidArgument };
+
+    let result = df_wrapper.parse_expr(&predicate_proto)
+        .and_then(|predicate| df_wrapper.transform(|df| df.filter(predicate)));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Groups the `DataFrame` rows and evaluates aggregate expressions for every group.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `aggregate_bytes` must be a valid `BytesData` containing a protobuf-encoded `AggregateExpressions`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_aggregate(
+    df_ptr: *mut DataFrameWrapper,
+    aggregate_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let Some(Ok(aggregate_proto)) = aggregate_bytes.as_opt_slice()
+        .map(proto::AggregateExpressions::decode) else { return crate::ErrorCode::InvalidArgument };
+
+    let result = df_wrapper.parse_exprs(&aggregate_proto.group_expr)
+        .and_then(|group_expr| Ok((group_expr, df_wrapper.parse_exprs(&aggregate_proto.aggr_expr)?)))
+        .and_then(|(group_expr, aggr_expr)| df_wrapper.transform(|df| df.aggregate(group_expr, aggr_expr)));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Sorts the `DataFrame` rows by a list of sort expressions.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `sort_exprs_bytes` must be a valid `BytesData` containing a protobuf-encoded `SortExprNodeCollection`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_sort(
+    df_ptr: *mut DataFrameWrapper,
+    sort_exprs_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let Some(Ok(sort_exprs_proto)) = sort_exprs_bytes.as_opt_slice()
+        .map(datafusion_proto::protobuf::SortExprNodeCollection::decode) else { return crate::ErrorCode::InvalidArgument };
+
+    let registry = df_wrapper.inner.task_ctx();
+    let result = mappers::from_proto_sort_exprs(&sort_exprs_proto.sort_expr_nodes, &registry)
+        .map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::InvalidArgument, e))
+        .and_then(|sort_exprs| df_wrapper.transform(|df| df.sort(sort_exprs)));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Skips and limits the number of `DataFrame` rows.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+///
+/// # Parameters
+/// - `skip`: Number of rows to skip
+/// - `fetch`: Maximum number of rows to return after skipping (negative = no limit)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_limit(
+    df_ptr: *mut DataFrameWrapper,
+    skip: u64,
+    fetch: i64,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let Ok(skip) = usize::try_from(skip) else { return crate::ErrorCode::InvalidArgument };
+    let fetch = usize::try_from(fetch).ok();
+
+    let result = df_wrapper.transform(|df| df.limit(skip, fetch));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Adds a column computed by an expression, replacing an existing column with the same name.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `name_ptr` must be a valid null-terminated UTF-8 string
+/// - `expr_bytes` must be a valid `BytesData` containing a protobuf-encoded `LogicalExprNode`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_with_column(
+    df_ptr: *mut DataFrameWrapper,
+    name_ptr: *const std::ffi::c_char,
+    expr_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+    let name = ffi_cstr_to_string!(name_ptr);
+
+    let Some(Ok(expr_proto)) = expr_bytes.as_opt_slice()
+        .map(datafusion_proto::protobuf::LogicalExprNode::decode) else { return crate::ErrorCode::InvalidArgument };
+
+    let result = df_wrapper.parse_expr(&expr_proto)
+        .and_then(|expr| df_wrapper.transform(|df| df.with_column(&name, expr)));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Removes columns by name. Names that do not match a column are ignored.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `columns_bytes` must be a valid `BytesData` containing a protobuf-encoded `ColumnNames`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_drop_columns(
+    df_ptr: *mut DataFrameWrapper,
+    columns_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let Some(Ok(columns_proto)) = columns_bytes.as_opt_slice()
+        .map(proto::ColumnNames::decode) else { return crate::ErrorCode::InvalidArgument };
+
+    let columns = columns_proto.names.iter().map(String::as_str).collect::<Vec<_>>();
+    let result = df_wrapper.transform(|df| df.drop_columns(&columns));
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Joins the `DataFrame` with another one on equal column values and an optional filter expression.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions, created by the same session
+/// - `join_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `JoinOptions`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_join(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    join_options_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let left_wrapper = ffi_ref!(left_ptr);
+    let right_wrapper = ffi_ref!(right_ptr);
+
+    let Some(Ok(join_options_proto)) = join_options_bytes.as_opt_slice()
+        .map(proto::JoinOptions::decode) else { return crate::ErrorCode::InvalidArgument };
+    let Ok(join_type) = datafusion_proto::protobuf::JoinType::try_from(join_options_proto.join_type) else { return crate::ErrorCode::InvalidArgument };
+
+    let result = left_wrapper.ensure_same_session(right_wrapper)
+        .and_then(|()| join_options_proto.filter.as_ref().map(|f| left_wrapper.parse_expr(f)).transpose())
+        .and_then(|filter| {
+            let left_cols = join_options_proto.left_cols.iter().map(String::as_str).collect::<Vec<_>>();
+            let right_cols = join_options_proto.right_cols.iter().map(String::as_str).collect::<Vec<_>>();
+            let right = right_wrapper.inner.clone();
+
+            left_wrapper.transform(|df| df.join(right, join_type.into(), &left_cols, &right_cols, filter))
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Joins every row of the `DataFrame` with every row of another one.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions, created by the same session
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_cross_join(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let left_wrapper = ffi_ref!(left_ptr);
+    let right_wrapper = ffi_ref!(right_ptr);
+
+    let result = left_wrapper.ensure_same_session(right_wrapper)
+        .and_then(|()| {
+            let right = right_wrapper.inner.clone();
+
+            // An inner join without keys or filter is planned as a cross join.
+            left_wrapper.transform(|df| df.join(right, datafusion::common::JoinType::Inner, &[], &[], None))
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Checks that the column types of a set operation can be coerced to common types, running the analyzer
+/// rules that would otherwise reject the combined `DataFrame` only when it is executed.
+fn check_set_operation(combined: &datafusion::prelude::DataFrame, operation: &str) -> Result<(), crate::ErrorInfo> {
+    let (state, plan) = combined.clone().into_parts();
+
+    state.analyzer()
+        .execute_and_check(plan, state.config_options(), |_, _| {})
+        .map(|_| ())
+        .map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::DataFrameError, format!(
+            "{operation} requires compatible column types: {}", e.find_root())))
+}
+
+/// Combines the rows of two `DataFrame`s with a set operation after checking that their schemas are compatible.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions
+unsafe fn invoke_set_operation<F>(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    operation: &str,
+    combine: F,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode
+where
+    F: FnOnce(datafusion::prelude::DataFrame, datafusion::prelude::DataFrame) -> datafusion::error::Result<datafusion::prelude::DataFrame>
+{
+    let left_wrapper = ffi_ref!(left_ptr);
+    let right_wrapper = ffi_ref!(right_ptr);
+
+    let result = left_wrapper.ensure_same_session(right_wrapper)
+        .and_then(|()| left_wrapper.ensure_same_column_count(right_wrapper, operation))
+        .and_then(|()| combine(left_wrapper.inner.clone(), right_wrapper.inner.clone())
+            .map_err(|e| crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, e)))
+        .and_then(|combined| {
+            check_set_operation(&combined, operation)?;
+            Ok(Box::into_raw(Box::new(DataFrameWrapper::new(Arc::clone(&left_wrapper.runtime), combined))))
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Appends the rows of another `DataFrame`, keeping duplicates (`UNION ALL`).
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions, created by the same session
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_union(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    unsafe { invoke_set_operation(left_ptr, right_ptr, "UNION ALL", datafusion::prelude::DataFrame::union, callback, user_data) }
+}
+
+/// Appends the rows of another `DataFrame` and removes duplicates (`UNION`).
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions, created by the same session
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_union_distinct(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    unsafe { invoke_set_operation(left_ptr, right_ptr, "UNION DISTINCT", datafusion::prelude::DataFrame::union_distinct, callback, user_data) }
+}
+
+/// Keeps the rows that also appear in another `DataFrame`, including duplicates (`INTERSECT ALL`).
+///
+/// Use `datafusion_dataframe_intersect_distinct` for the SQL default `INTERSECT`, which removes duplicates.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions, created by the same session
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_intersect(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    unsafe { invoke_set_operation(left_ptr, right_ptr, "INTERSECT ALL", datafusion::prelude::DataFrame::intersect, callback, user_data) }
+}
+
+/// Keeps the rows that do not appear in another `DataFrame`, including duplicates (`EXCEPT ALL`).
+///
+/// Use `datafusion_dataframe_except_distinct` for the SQL default `EXCEPT`, which removes duplicates.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions, created by the same session
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_except(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    unsafe { invoke_set_operation(left_ptr, right_ptr, "EXCEPT ALL", datafusion::prelude::DataFrame::except, callback, user_data) }
+}
+
+/// Keeps the distinct rows that also appear in another `DataFrame` (`INTERSECT DISTINCT`).
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions, created by the same session
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_intersect_distinct(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    unsafe { invoke_set_operation(left_ptr, right_ptr, "INTERSECT DISTINCT", datafusion::prelude::DataFrame::intersect_distinct, callback, user_data) }
+}
+
+/// Keeps the distinct rows that do not appear in another `DataFrame` (`EXCEPT DISTINCT`).
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `left_ptr` and `right_ptr` must be valid pointers returned by other public functions, created by the same session
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_except_distinct(
+    left_ptr: *mut DataFrameWrapper,
+    right_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    unsafe { invoke_set_operation(left_ptr, right_ptr, "EXCEPT DISTINCT", datafusion::prelude::DataFrame::except_distinct, callback, user_data) }
+}
+
+/// Removes duplicate rows.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_distinct(
+    df_ptr: *mut DataFrameWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let result = df_wrapper.transform(datafusion::prelude::DataFrame::distinct);
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Keeps one row for every distinct value of the `ON` expressions (`SELECT DISTINCT ON (...) ...`).
+///
+/// This is a synchronous operation. The callback is invoked immediately with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `distinct_on_bytes` must be a valid `BytesData` containing a protobuf-encoded `DistinctOnExpressions`
+/// - `callback` must be valid to call from the current thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_distinct_on(
+    df_ptr: *mut DataFrameWrapper,
+    distinct_on_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let Some(Ok(distinct_on_proto)) = distinct_on_bytes.as_opt_slice()
+        .map(proto::DistinctOnExpressions::decode) else { return crate::ErrorCode::InvalidArgument };
+
+    let result = (|| {
+        let on_expr = df_wrapper.parse_exprs(&distinct_on_proto.on_expr)?;
+        let select_expr = df_wrapper.parse_exprs(&distinct_on_proto.select_expr)?;
+        let sort_expr = mappers::from_proto_sort_exprs(&distinct_on_proto.sort_expr, &df_wrapper.inner.task_ctx())
+            .map_err(|e| crate::ErrorInfo::new(crate::ErrorCode::InvalidArgument, e))?;
+        let sort_expr = (!sort_expr.is_empty()).then_some(sort_expr);
+
+        df_wrapper.transform(|df| df.distinct_on(on_expr, select_expr, sort_expr))
+    })();
+
+    crate::invoke_callback(result, callback, user_data);
+
+    crate::ErrorCode::Ok
+}
+
+/// Computes summary statistics (count, `null_count`, mean, std, min, max and median) for every column.
+///
+/// This is an async operation, as the statistics are computed by executing the `DataFrame`. The callback is
+/// invoked on completion with a new `DataFrame` pointer holding one row per statistic, named by a `describe`
+/// column, which can be collected like any other `DataFrame`.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+///
+/// # Parameters
+/// - `timeout_millis`: Deadline for the operation in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_describe(
+    df_ptr: *mut DataFrameWrapper,
+    timeout_millis: u64,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    dev_msg!("Executing describe on DataFrame: {:p}", df_ptr);
+
+    crate::spawn_operation_with_timeout(&df_wrapper.runtime, callback, user_data, timeout_millis, async move {
+        let df = df_wrapper.inner.clone();
+        let result = df
+            .describe()
+            .await
+            .map(|df| Box::into_raw(Box::new(DataFrameWrapper::new(Arc::clone(&df_wrapper.runtime), df))))
+            .map_err(|e| crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, e));
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    crate::ErrorCode::Ok
+}
+
+/// Executes the `DataFrame` once and returns a new `DataFrame` backed by the materialized record batches, so
+/// that it can be consumed repeatedly without re-executing the plan.
+///
+/// This is an async operation. The callback is invoked on completion with a new `DataFrame` pointer.
+///
+/// # Safety
+/// - `df_ptr` must be a valid pointer returned by other public functions
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+///
+/// # Parameters
+/// - `memory_limit`: Maximum size of the materialized batches in bytes (0 = no limit); when exceeded the
+///   callback receives `ErrorCode::ResourcesExhausted` and no batches are kept
+/// - `timeout_millis`: Deadline for the operation in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_dataframe_cache(
+    df_ptr: *mut DataFrameWrapper,
+    memory_limit: u64,
+    timeout_millis: u64,
+    callback: crate::Callback,
+    user_data: u64
+) -> crate::ErrorCode {
+    let df_wrapper = ffi_ref!(df_ptr);
+
+    let Ok(memory_limit) = usize::try_from(memory_limit) else { return crate::ErrorCode::InvalidArgument };
+
+    dev_msg!("Caching DataFrame: {:p}", df_ptr);
+
+    crate::spawn_operation_with_timeout(&df_wrapper.runtime, callback, user_data, timeout_millis, async move {
+        let df = df_wrapper.inner.clone();
+        let result = if memory_limit == 0 { df.cache().await } else { cache_with_memory_limit(df, memory_limit).await };
+        let result = result
+            .map(|df| Box::into_raw(Box::new(DataFrameWrapper::new(Arc::clone(&df_wrapper.runtime), df))))
+            .map_err(|e| crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, e));
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    crate::ErrorCode::Ok
+}
+
+/// Materializes the `DataFrame` into memory like `DataFrame::cache`, failing as soon as the collected batches
+/// exceed `memory_limit` bytes.
+async fn cache_with_memory_limit(
+    df: datafusion::prelude::DataFrame,
+    memory_limit: usize
+) -> datafusion::error::Result<datafusion::prelude::DataFrame> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let task_ctx = Arc::new(df.task_ctx());
+    let (state, _) = df.clone().into_parts();
+    let plan = df.create_physical_plan().await?;
+    let schema = plan.schema();
+
+    let used = AtomicUsize::new(0);
+    let streams = datafusion::physical_plan::execute_stream_partitioned(plan, task_ctx)?;
+    let partitions = futures::future::try_join_all(streams.into_iter().map(|mut stream| {
+        let used = &used;
+        async move {
+            let mut batches = Vec::new();
+            while let Some(batch) = stream.next().await.transpose()? {
+                let size = batch.get_array_memory_size();
+                if used.fetch_add(size, Ordering::Relaxed) + size > memory_limit {
+                    return datafusion::common::resources_err!(
+                        "Cached DataFrame exceeds the memory limit of {memory_limit} bytes");
+                }
+                batches.push(batch);
+            }
+            Ok(batches)
+        }
+    })).await?;
+
+    let mem_table = datafusion::datasource::MemTable::try_new(schema, partitions)?;
+    datafusion::prelude::SessionContext::new_with_state(state).read_table(Arc::new(mem_table))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::c_void;
+
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::test_util::call;
+
+    /// Reads a `DataFrame` result and destroys it right away.
+    fn destroy(result: *const c_void) {
+        unsafe { datafusion_dataframe_destroy(*result.cast::<*mut DataFrameWrapper>()) };
+    }
+
+    fn new_runtime() -> crate::RuntimeHandle {
+        Arc::new(tokio::runtime::Builder::new_current_thread().build().unwrap())
+    }
+
+    fn new_dataframe(runtime: &crate::RuntimeHandle, context: &SessionContext, sql: &str) -> DataFrameWrapper {
+        let df = runtime.block_on(context.sql(sql)).unwrap();
+        DataFrameWrapper::new(Arc::clone(runtime), df)
+    }
+
+    #[test]
+    fn ensure_same_session_rejects_dataframes_of_different_sessions() {
+        let runtime = new_runtime();
+        let context = SessionContext::new();
+        let left = new_dataframe(&runtime, &context, "SELECT 1 AS a");
+        let same_session = new_dataframe(&runtime, &context, "SELECT 2 AS a");
+        let other_session = new_dataframe(&runtime, &SessionContext::new(), "SELECT 3 AS a");
+
+        assert!(left.ensure_same_session(&same_session).is_ok());
+
+        let error = left.ensure_same_session(&other_session).unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::InvalidArgument);
+        assert!(error.message().starts_with("DataFrames belong to different sessions"), "{}", error.message());
+    }
+
+    #[test]
+    fn join_rejects_dataframes_of_different_sessions() {
+        let runtime = new_runtime();
+        let context = SessionContext::new();
+        let mut left = new_dataframe(&runtime, &context, "SELECT 1 AS a");
+        let mut same_session = new_dataframe(&runtime, &context, "SELECT 1 AS b");
+        let mut other_session = new_dataframe(&runtime, &SessionContext::new(), "SELECT 1 AS b");
+        let options = proto::JoinOptions {
+            join_type: datafusion_proto::protobuf::JoinType::Inner.into(),
+            left_cols: vec!["a".to_string()],
+            right_cols: vec!["b".to_string()],
+            filter: None
+        }.encode_to_vec();
+
+        let mut join = |right: &mut DataFrameWrapper| call(destroy, |callback, user_data| unsafe {
+            datafusion_dataframe_join(&raw mut left, right, crate::BytesData::new(&options), callback, user_data)
+        });
+
+        assert!(join(&mut same_session).is_ok());
+        assert_eq!(join(&mut other_session).unwrap_err().code(), crate::ErrorCode::InvalidArgument);
+
+        let error = call(destroy, |callback, user_data| unsafe {
+            datafusion_dataframe_cross_join(&raw mut left, &raw mut other_session, callback, user_data)
+        }).unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::InvalidArgument);
+    }
+
+    /// Combines the results of two queries of one session with a set operation, e.g. `datafusion_dataframe_union`.
+    fn combine(
+        operation: unsafe extern "C" fn(*mut DataFrameWrapper, *mut DataFrameWrapper, crate::Callback, u64) -> crate::ErrorCode,
+        left_sql: &str,
+        right_sql: &str
+    ) -> Result<(), crate::ErrorInfo> {
+        let runtime = new_runtime();
+        let context = SessionContext::new();
+        let mut left = new_dataframe(&runtime, &context, left_sql);
+        let mut right = new_dataframe(&runtime, &context, right_sql);
+
+        call(destroy, |callback, user_data| unsafe { operation(&raw mut left, &raw mut right, callback, user_data) })
+    }
+
+    #[test]
+    fn set_operations_accept_coercible_column_types() {
+        let left = "SELECT CAST(1 AS INT) AS a, 'x' AS b";
+        let right = "SELECT CAST(2 AS BIGINT) AS c, 'y' AS d";
+
+        assert!(combine(datafusion_dataframe_union, left, right).is_ok());
+        assert!(combine(datafusion_dataframe_except, left, right).is_ok());
+    }
+
+    #[test]
+    fn set_operations_name_columns_when_counts_differ() {
+        let error = combine(datafusion_dataframe_union, "SELECT 1 AS a, 2 AS b", "SELECT 1 AS c").unwrap_err();
+
+        assert_eq!(error.code(), crate::ErrorCode::DataFrameError);
+        assert_eq!(error.message(), "UNION ALL requires the same number of columns, but left has 2 (a, b) and right has 1 (c)");
+    }
+
+    #[test]
+    fn set_operations_reject_incompatible_column_types() {
+        let error = combine(datafusion_dataframe_except, "SELECT 1 AS a, true AS b", "SELECT 2 AS a, make_array(1) AS c").unwrap_err();
+
+        assert_eq!(error.code(), crate::ErrorCode::DataFrameError);
+        assert!(error.message().starts_with("EXCEPT ALL requires compatible column types"), "{}", error.message());
+    }
+
+    #[test]
+    fn set_operations_reject_types_that_only_comparisons_coerce() {
+        use datafusion::arrow::datatypes::{DataType, TimeUnit};
+        use datafusion::logical_expr::type_coercion::binary::comparison_coercion;
+
+        // Comparisons coerce dates and times to timestamps, but set operations cannot convert the time
+        assert!(comparison_coercion(&DataType::Date32, &DataType::Time64(TimeUnit::Nanosecond)).is_some());
+
+        let left = "SELECT CAST('2024-01-01' AS DATE) AS a";
+        let right = "SELECT arrow_cast('01:00:00', 'Time64(Nanosecond)') AS a";
+
+        for (operation, name) in [
+            (datafusion_dataframe_union as unsafe extern "C" fn(_, _, _, _) -> _, "UNION ALL"),
+            (datafusion_dataframe_intersect_distinct, "INTERSECT DISTINCT")
+        ] {
+            let error = combine(operation, left, right).unwrap_err();
+
+            assert_eq!(error.code(), crate::ErrorCode::DataFrameError);
+            assert!(error.message().starts_with(&format!("{name} requires compatible column types")), "{}", error.message());
+        }
+    }
+
+    #[test]
+    fn cache_with_memory_limit_keeps_batches_within_budget() {
+        let runtime = new_runtime();
+        let df = runtime.block_on(SessionContext::new().sql("SELECT * FROM generate_series(1, 10)")).unwrap();
+
+        let cached = runtime.block_on(cache_with_memory_limit(df, 1 << 20)).unwrap();
+
+        assert_eq!(runtime.block_on(cached.clone().count()).unwrap(), 10);
+        assert_eq!(runtime.block_on(cached.count()).unwrap(), 10);
+    }
+
+    #[test]
+    fn cache_with_memory_limit_reports_exceeded_budget_as_resources_exhausted() {
+        let runtime = new_runtime();
+        let df = runtime.block_on(SessionContext::new().sql("SELECT * FROM generate_series(1, 10)")).unwrap();
+
+        let error = runtime.block_on(cache_with_memory_limit(df, 1)).unwrap_err();
+        assert!(matches!(error, datafusion::error::DataFusionError::ResourcesExhausted(_)), "{error}");
+
+        let error = crate::ErrorInfo::from_datafusion(crate::ErrorCode::DataFrameError, error);
+        assert_eq!(error.code(), crate::ErrorCode::ResourcesExhausted);
+        assert!(error.message().contains("Cached DataFrame exceeds the memory limit of 1 bytes"), "{}", error.message());
+    }
+}
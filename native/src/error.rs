@@ -8,7 +8,12 @@ pub enum ErrorCode {
     RuntimeShutdownFailed = 4,
     TableRegistrationFailed = 5,
     SqlError = 6,
-    DataFrameError = 7
+    DataFrameError = 7,
+    CatalogError = 8,
+    ResourcesExhausted = 9,
+    Cancelled = 10,
+    Timeout = 11,
+    StatementNotAllowed = 12
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +30,23 @@ impl ErrorInfo {
         }
     }
     
+    /// Creates an error from a `DataFusionError`, keeping the code of an `ErrorInfo` raised while planning
+    /// or executing, e.g. by a table provider, and reporting resource exhaustion, e.g. an exceeded memory
+    /// limit, with `ErrorCode::ResourcesExhausted` instead of `code`.
+    pub fn from_datafusion(code: ErrorCode, error: datafusion::error::DataFusionError) -> Self {
+        if let datafusion::error::DataFusionError::External(inner) = error.find_root()
+            && let Some(info) = inner.downcast_ref::<ErrorInfo>() {
+            return info.clone();
+        }
+
+        let code = match error.find_root() {
+            datafusion::error::DataFusionError::ResourcesExhausted(_) => ErrorCode::ResourcesExhausted,
+            _ => code
+        };
+
+        Self::new(code, error)
+    }
+
     pub fn code(&self) -> ErrorCode {
         self.code
     }
@@ -33,3 +55,11 @@ impl ErrorInfo {
         &self.message
     }
 }
+
+impl std::fmt::Display for ErrorInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ErrorInfo {}
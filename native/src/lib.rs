@@ -1,21 +1,40 @@
 #[macro_use]
 mod macros;
 
+#[allow(clippy::doc_markdown, clippy::must_use_candidate)] // prost-generated enum helpers
 pub mod proto {
     include!(concat!(env!("OUT_DIR"), "/datafusion_sharp_proto.rs"));
 }
 
 mod mappers;
+mod arrow_ffi;
+mod streaming;
+#[cfg(test)]
+mod test_util;
 pub mod error;
 pub mod common;
 pub mod runtime;
+pub mod operation;
 pub mod context;
 pub mod dataframe;
+pub mod prepared_statement;
+pub mod script;
+pub mod udf;
+pub mod udaf;
+pub mod udwf;
+pub mod udtf;
 
 pub use proto::*;
 pub use error::*;
 pub use common::*;
 
 pub use runtime::*;
+pub use operation::*;
 pub use context::*;
 pub use dataframe::*;
+pub use prepared_statement::*;
+pub use script::*;
+pub use udf::*;
+pub use udaf::*;
+pub use udwf::*;
+pub use udtf::*;
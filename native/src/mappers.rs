@@ -1,11 +1,23 @@
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 use anyhow::{anyhow, bail, Result};
 
 use datafusion::arrow::datatypes::{DataType, Schema};
 use datafusion::common::{ParamValues, ScalarValue};
+use datafusion::common::config::{ConfigField, ParquetColumnOptions, TableParquetOptions};
 use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
-use datafusion::logical_expr::SortExpr;
-use datafusion::prelude::CsvReadOptions;
+use datafusion::datasource::file_format::options::ArrowReadOptions;
+use datafusion::execution::cache::cache_manager::CacheManagerConfig;
+use datafusion::execution::cache::cache_unit::DefaultFileStatisticsCache;
+use datafusion::execution::disk_manager::{DiskManagerBuilder, DiskManagerMode};
+use datafusion::execution::FunctionRegistry;
+use datafusion::execution::memory_pool::{FairSpillPool, GreedyMemoryPool, TrackConsumersPool};
+use datafusion::execution::runtime_env::{RuntimeEnv, RuntimeEnvBuilder};
+use datafusion::logical_expr::{Expr, SortExpr, Volatility};
+use datafusion::prelude::{CsvReadOptions, SQLOptions};
 use crate::proto;
 
 pub(crate) fn from_proto_schema(schema: Option<&datafusion_proto::protobuf::Schema>) -> Result<Option<Schema>> {
@@ -15,6 +27,76 @@ pub(crate) fn from_proto_schema(schema: Option<&datafusion_proto::protobuf::Sche
         .map_err(|e| anyhow!("Failed to parse schema from options: {e}"))
 }
 
+/// Protobuf read options of a file format that convert into the matching `DataFusion` read options.
+///
+/// The `DataFusion` options borrow their schema, so the schema embedded in the message is parsed separately first.
+pub(crate) trait ProtoReadOptions {
+    type Options<'a> where Self: 'a;
+
+    /// Format name used in error messages.
+    const FORMAT: &'static str;
+
+    fn schema(&self) -> Option<&datafusion_proto::protobuf::Schema>;
+
+    fn to_read_options<'a>(pbo: Option<&'a Self>, schema: Option<&'a Schema>) -> Result<Self::Options<'a>>;
+}
+
+impl ProtoReadOptions for proto::CsvReadOptions {
+    type Options<'a> = CsvReadOptions<'a>;
+
+    const FORMAT: &'static str = "CSV";
+
+    fn schema(&self) -> Option<&datafusion_proto::protobuf::Schema> {
+        self.schema.as_ref()
+    }
+
+    fn to_read_options<'a>(pbo: Option<&'a Self>, schema: Option<&'a Schema>) -> Result<Self::Options<'a>> {
+        from_proto_csv_options(pbo, schema)
+    }
+}
+
+impl ProtoReadOptions for proto::JsonReadOptions {
+    type Options<'a> = datafusion::prelude::NdJsonReadOptions<'a>;
+
+    const FORMAT: &'static str = "JSON";
+
+    fn schema(&self) -> Option<&datafusion_proto::protobuf::Schema> {
+        self.schema.as_ref()
+    }
+
+    fn to_read_options<'a>(pbo: Option<&'a Self>, schema: Option<&'a Schema>) -> Result<Self::Options<'a>> {
+        from_proto_json_read_options(pbo, schema)
+    }
+}
+
+impl ProtoReadOptions for proto::ParquetReadOptions {
+    type Options<'a> = datafusion::prelude::ParquetReadOptions<'a>;
+
+    const FORMAT: &'static str = "Parquet";
+
+    fn schema(&self) -> Option<&datafusion_proto::protobuf::Schema> {
+        self.schema.as_ref()
+    }
+
+    fn to_read_options<'a>(pbo: Option<&'a Self>, schema: Option<&'a Schema>) -> Result<Self::Options<'a>> {
+        from_proto_parquet_read_options(pbo, schema)
+    }
+}
+
+impl ProtoReadOptions for proto::ArrowReadOptions {
+    type Options<'a> = ArrowReadOptions<'a>;
+
+    const FORMAT: &'static str = "Arrow";
+
+    fn schema(&self) -> Option<&datafusion_proto::protobuf::Schema> {
+        self.schema.as_ref()
+    }
+
+    fn to_read_options<'a>(pbo: Option<&'a Self>, schema: Option<&'a Schema>) -> Result<Self::Options<'a>> {
+        from_proto_arrow_read_options(pbo, schema)
+    }
+}
+
 #[warn(clippy::field_reassign_with_default)]
 pub(crate) fn from_proto_csv_options<'a>(
     pbo: Option<&'a proto::CsvReadOptions>,
@@ -82,6 +164,204 @@ pub(crate) fn from_proto_json_read_options<'a>(
     Ok(dfo)
 }
 
+#[warn(clippy::field_reassign_with_default)]
+pub(crate) fn from_proto_parquet_read_options<'a>(
+    pbo: Option<&'a proto::ParquetReadOptions>,
+    schema: Option<&'a Schema>
+) -> Result<datafusion::prelude::ParquetReadOptions<'a>> {
+    let mut dfo = datafusion::prelude::ParquetReadOptions::default();
+    let Some(pbo) = pbo else { return Ok(dfo) };
+
+    if let Some(file_extension) = pbo.file_extension.as_ref() && !file_extension.is_empty() {
+        dfo.file_extension = std::str::from_utf8(file_extension)?;
+    }
+    dfo.table_partition_cols = from_proto_table_partition_cols(&pbo.table_partition_cols)?;
+    dfo.parquet_pruning = pbo.parquet_pruning;
+    dfo.skip_metadata = pbo.skip_metadata;
+    dfo.schema = schema;
+    dfo.file_sort_order = from_proto_file_sort_order(&pbo.file_sort_order)?;
+    dfo.metadata_size_hint = pbo.metadata_size_hint.map(usize::try_from).transpose()?;
+
+    Ok(dfo)
+}
+
+pub(crate) fn from_proto_session_config(pbo: Option<&proto::SessionConfig>) -> Result<datafusion::prelude::SessionConfig> {
+    let Some(pbo) = pbo else { return Ok(datafusion::prelude::SessionConfig::new()) };
+
+    let mut dfo = datafusion::prelude::SessionConfig::from_string_hash_map(&pbo.options)?;
+
+    if let Some(target_partitions) = pbo.target_partitions {
+        dfo = dfo.with_target_partitions(usize::try_from(target_partitions)?);
+    }
+    if let Some(batch_size) = pbo.batch_size {
+        if batch_size == 0 {
+            bail!("batch_size must be greater than 0");
+        }
+        dfo = dfo.with_batch_size(usize::try_from(batch_size)?);
+    }
+    if let Some(information_schema) = pbo.information_schema {
+        dfo = dfo.with_information_schema(information_schema);
+    }
+    if let Some(default_catalog) = pbo.default_catalog.as_ref() {
+        dfo.options_mut().catalog.default_catalog.clone_from(default_catalog);
+    }
+    if let Some(default_schema) = pbo.default_schema.as_ref() {
+        dfo.options_mut().catalog.default_schema.clone_from(default_schema);
+    }
+    if let Some(enable_ident_normalization) = pbo.enable_ident_normalization {
+        dfo.options_mut().sql_parser.enable_ident_normalization = enable_ident_normalization;
+    }
+    if let Some(dialect) = pbo.dialect.as_ref() {
+        dfo.options_mut().sql_parser.dialect = dialect.parse()?;
+    }
+
+    Ok(dfo)
+}
+
+/// Number of top memory consumers reported when a bounded memory pool is exhausted.
+const TRACKED_CONSUMERS: NonZeroUsize = NonZeroUsize::new(5).unwrap();
+
+pub(crate) fn from_proto_runtime_env(pbo: Option<&proto::RuntimeEnvConfig>) -> Result<Arc<RuntimeEnv>> {
+    let Some(pbo) = pbo else { return Ok(Arc::new(RuntimeEnv::default())) };
+
+    let mut builder = RuntimeEnvBuilder::new();
+
+    let memory_limit = pbo.memory_limit.map(usize::try_from).transpose()?;
+    let pool_type = proto::MemoryPoolType::try_from(pbo.memory_pool_type)
+        .map_err(|_| anyhow!("invalid MemoryPoolType value: {}", pbo.memory_pool_type))?;
+
+    match (pool_type, memory_limit) {
+        (proto::MemoryPoolType::Unbounded, None) => {}
+        (proto::MemoryPoolType::Unbounded, Some(_)) => bail!("memory_limit requires a greedy or fair memory pool"),
+        (_, None) => bail!("memory_limit is required for a greedy or fair memory pool"),
+        (proto::MemoryPoolType::Greedy, Some(limit)) => {
+            builder = builder.with_memory_pool(Arc::new(TrackConsumersPool::new(GreedyMemoryPool::new(limit), TRACKED_CONSUMERS)));
+        }
+        (proto::MemoryPoolType::Fair, Some(limit)) => {
+            builder = builder.with_memory_pool(Arc::new(TrackConsumersPool::new(FairSpillPool::new(limit), TRACKED_CONSUMERS)));
+        }
+    }
+
+    let mut disk_manager = DiskManagerBuilder::default();
+    if pbo.disable_spilling == Some(true) {
+        if !pbo.spill_directories.is_empty() {
+            bail!("spill_directories cannot be set when spilling is disabled");
+        }
+        disk_manager = disk_manager.with_mode(DiskManagerMode::Disabled);
+    } else if !pbo.spill_directories.is_empty() {
+        disk_manager = disk_manager.with_mode(DiskManagerMode::Directories(pbo.spill_directories.iter().map(PathBuf::from).collect()));
+    }
+    if let Some(max_temp_directory_size) = pbo.max_temp_directory_size {
+        disk_manager = disk_manager.with_max_temp_directory_size(max_temp_directory_size);
+    }
+    builder = builder.with_disk_manager_builder(disk_manager);
+
+    if let Some(cache) = pbo.cache_manager.as_ref() {
+        builder = builder.with_cache_manager(from_proto_cache_manager_config(cache)?);
+    }
+
+    Ok(builder.build_arc()?)
+}
+
+fn from_proto_cache_manager_config(pbo: &proto::CacheManagerConfig) -> Result<CacheManagerConfig> {
+    let mut dfo = CacheManagerConfig::default();
+
+    if pbo.enable_file_statistics_cache == Some(true) {
+        dfo = dfo.with_files_statistics_cache(Some(Arc::new(DefaultFileStatisticsCache::default())));
+    }
+    if let Some(list_files_cache_limit) = pbo.list_files_cache_limit {
+        dfo = dfo.with_list_files_cache_limit(usize::try_from(list_files_cache_limit)?);
+    }
+    if let Some(list_files_cache_ttl_millis) = pbo.list_files_cache_ttl_millis {
+        dfo = dfo.with_list_files_cache_ttl(Some(Duration::from_millis(list_files_cache_ttl_millis)));
+    }
+    if let Some(metadata_cache_limit) = pbo.metadata_cache_limit {
+        dfo = dfo.with_metadata_cache_limit(usize::try_from(metadata_cache_limit)?);
+    }
+
+    Ok(dfo)
+}
+
+#[warn(clippy::field_reassign_with_default)]
+pub(crate) fn from_proto_arrow_read_options<'a>(
+    pbo: Option<&'a proto::ArrowReadOptions>,
+    schema: Option<&'a Schema>
+) -> Result<ArrowReadOptions<'a>> {
+    let mut dfo = ArrowReadOptions::default();
+    let Some(pbo) = pbo else { return Ok(dfo) };
+
+    if let Some(file_extension) = pbo.file_extension.as_ref() && !file_extension.is_empty() {
+        dfo.file_extension = std::str::from_utf8(file_extension)?;
+    }
+    dfo.table_partition_cols = from_proto_table_partition_cols(&pbo.table_partition_cols)?;
+    dfo.schema = schema;
+
+    Ok(dfo)
+}
+
+/// Applies the fields set in `pbo` on top of `dfo`, which holds the session's Parquet writer options.
+pub(crate) fn from_proto_parquet_write_options(
+    pbo: &proto::ParquetWriteOptions,
+    mut dfo: TableParquetOptions
+) -> Result<TableParquetOptions> {
+    let global = &mut dfo.global;
+
+    // String options go through `ConfigField::set` to get the same parsing and validation as SQL `OPTIONS`
+    for (key, value) in [
+        ("compression", &pbo.compression),
+        ("statistics_enabled", &pbo.statistics_enabled),
+        ("writer_version", &pbo.writer_version),
+        ("encoding", &pbo.encoding),
+    ] {
+        if let Some(value) = value {
+            global.set(key, value).map_err(|e| anyhow!("Invalid Parquet option '{key}': {e}"))?;
+        }
+    }
+
+    if let Some(dictionary_enabled) = pbo.dictionary_enabled {
+        global.dictionary_enabled = Some(dictionary_enabled);
+    }
+    if let Some(max_row_group_size) = pbo.max_row_group_size {
+        global.max_row_group_size = usize::try_from(max_row_group_size)?;
+    }
+    if let Some(data_page_size_limit) = pbo.data_page_size_limit {
+        global.data_pagesize_limit = usize::try_from(data_page_size_limit)?;
+    }
+    if let Some(data_page_row_count_limit) = pbo.data_page_row_count_limit {
+        global.data_page_row_count_limit = usize::try_from(data_page_row_count_limit)?;
+    }
+    if let Some(dictionary_page_size_limit) = pbo.dictionary_page_size_limit {
+        global.dictionary_page_size_limit = usize::try_from(dictionary_page_size_limit)?;
+    }
+    if let Some(write_batch_size) = pbo.write_batch_size {
+        global.write_batch_size = usize::try_from(write_batch_size)?;
+    }
+    if let Some(bloom_filter_on_write) = pbo.bloom_filter_on_write {
+        global.bloom_filter_on_write = bloom_filter_on_write;
+    }
+    if let Some(bloom_filter_fpp) = pbo.bloom_filter_fpp {
+        global.bloom_filter_fpp = Some(bloom_filter_fpp);
+    }
+    if let Some(bloom_filter_ndv) = pbo.bloom_filter_ndv {
+        global.bloom_filter_ndv = Some(bloom_filter_ndv);
+    }
+    if let Some(created_by) = pbo.created_by.as_ref() {
+        global.created_by.clone_from(created_by);
+    }
+
+    for (key, value) in &pbo.key_value_metadata {
+        dfo.key_value_metadata.insert(key.clone(), Some(value.clone()));
+    }
+    for column in &pbo.column_options {
+        let options = column.options.clone()
+            .map(ParquetColumnOptions::from)
+            .unwrap_or_default();
+        dfo.column_specific_options.insert(column.column_name.clone(), options);
+    }
+
+    Ok(dfo)
+}
+
 #[warn(clippy::field_reassign_with_default)]
 pub(crate) fn from_proto_dataframe_write_options(pbo: Option<&proto::DataFrameWriteOptions>) -> Result<datafusion::dataframe::DataFrameWriteOptions> {
     let dfo = datafusion::dataframe::DataFrameWriteOptions::default();
@@ -125,34 +405,43 @@ fn from_proto_table_partition_cols(table_partition_cols: &[datafusion_proto::pro
 }
 
 fn from_proto_file_sort_order(file_sort_order: &[datafusion_proto::protobuf::SortExprNodeCollection]) -> Result<Vec<Vec<SortExpr>>> {
-    let codec = datafusion_proto::logical_plan::DefaultLogicalExtensionCodec {};
-    let registry_impl = datafusion::execution::registry::MemoryFunctionRegistry::new();
-    let registry: &dyn datafusion::execution::FunctionRegistry = &registry_impl;
+    let registry = datafusion::execution::registry::MemoryFunctionRegistry::new();
 
     file_sort_order.iter()
-        .map(|order| {
-            order
-                .sort_expr_nodes
-                .iter()
-                .map(|node| {
-                    let expr_node = node
-                        .expr
-                        .as_ref()
-                        .ok_or_else(|| anyhow!("Missing sort expression"))?;
-
-                    let expr = datafusion_proto::logical_plan::from_proto::parse_expr(expr_node, registry, &codec)?;
-
-                    Ok(SortExpr {
-                        expr,
-                        asc: node.asc,
-                        nulls_first: node.nulls_first,
-                    })
-                })
-                .collect::<Result<Vec<_>>>()
-        })
+        .map(|order| from_proto_sort_exprs(&order.sort_expr_nodes, &registry))
         .collect::<Result<Vec<_>>>()
 }
 
+/// Decodes a serialized logical expression, resolving function calls through `registry`.
+pub(crate) fn from_proto_expr(node: &datafusion_proto::protobuf::LogicalExprNode, registry: &dyn FunctionRegistry) -> Result<Expr> {
+    let codec = datafusion_proto::logical_plan::DefaultLogicalExtensionCodec {};
+    datafusion_proto::logical_plan::from_proto::parse_expr(node, registry, &codec)
+        .map_err(|e| anyhow!("Failed to parse expression: {e}"))
+}
+
+pub(crate) fn from_proto_exprs(nodes: &[datafusion_proto::protobuf::LogicalExprNode], registry: &dyn FunctionRegistry) -> Result<Vec<Expr>> {
+    nodes.iter()
+        .map(|node| from_proto_expr(node, registry))
+        .collect()
+}
+
+pub(crate) fn from_proto_sort_exprs(nodes: &[datafusion_proto::protobuf::SortExprNode], registry: &dyn FunctionRegistry) -> Result<Vec<SortExpr>> {
+    nodes.iter()
+        .map(|node| {
+            let expr_node = node
+                .expr
+                .as_ref()
+                .ok_or_else(|| anyhow!("Missing sort expression"))?;
+
+            Ok(SortExpr {
+                expr: from_proto_expr(expr_node, registry)?,
+                asc: node.asc,
+                nulls_first: node.nulls_first,
+            })
+        })
+        .collect()
+}
+
 fn from_proto_file_compression(v: i32) -> Result<FileCompressionType> {
     use datafusion_proto::generated::datafusion_common::CompressionTypeVariant as PbCompression;
 
@@ -187,6 +476,22 @@ fn from_proto_insert_op(v: i32) -> Result<datafusion::logical_expr::dml::InsertO
 }
 
 pub(crate) fn from_proto_sql_params(params: &proto::SqlParameters) -> Result<ParamValues> {
+    if !params.positional_values.is_empty() {
+        if !params.values.is_empty() {
+            bail!("Named and positional parameters cannot be combined");
+        }
+
+        let list = params.positional_values.iter()
+            .enumerate()
+            .map(|(i, proto_val)| {
+                ScalarValue::try_from(proto_val)
+                    .map_err(|e| anyhow!("Failed to convert parameter '${}': {e}", i + 1))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        return Ok(list.into());
+    }
+
     let map: HashMap<String, ScalarValue> = params.values.iter()
         .map(|(key, proto_val)| {
             let scalar = ScalarValue::try_from(proto_val)
@@ -196,4 +501,120 @@ pub(crate) fn from_proto_sql_params(params: &proto::SqlParameters) -> Result<Par
         .collect::<Result<_>>()?;
 
     Ok(map.into())
-}
\ No newline at end of file
+}
+
+pub(crate) fn from_proto_sql_options(pbo: &proto::SqlOptions) -> SQLOptions {
+    SQLOptions::new()
+        .with_allow_ddl(pbo.allow_ddl.unwrap_or(true))
+        .with_allow_dml(pbo.allow_dml.unwrap_or(true))
+        .with_allow_statements(pbo.allow_statements.unwrap_or(true))
+}
+
+pub(crate) fn to_proto_sql_parameter_types(types: HashMap<String, Option<DataType>>) -> Result<proto::SqlParameterTypes> {
+    let mut parameters = types.into_iter()
+        .map(|(id, data_type)| {
+            let data_type = data_type.as_ref()
+                .map(datafusion_proto::protobuf::ArrowType::try_from)
+                .transpose()
+                .map_err(|e| anyhow!("Failed to convert type of parameter '{id}': {e}"))?;
+            Ok(proto::SqlParameterType { id, data_type })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Positional placeholders ($1, $2, ...) sort numerically before named ones
+    parameters.sort_by_cached_key(|p| {
+        let position = p.id.strip_prefix('$').and_then(|n| n.parse::<u64>().ok());
+        (position.is_none(), position, p.id.clone())
+    });
+
+    Ok(proto::SqlParameterTypes { parameters })
+}
+
+pub(crate) fn from_proto_arrow_type(arrow_type: Option<&datafusion_proto::protobuf::ArrowType>, field: &str) -> Result<DataType> {
+    let arrow_type = arrow_type.ok_or_else(|| anyhow!("Missing arrow type for {field}"))?;
+
+    DataType::try_from(arrow_type)
+        .map_err(|e| anyhow!("Invalid arrow type for {field}: {e}"))
+}
+
+pub(crate) fn from_proto_arrow_types(arrow_types: &[datafusion_proto::protobuf::ArrowType], field: &str) -> Result<Vec<DataType>> {
+    arrow_types.iter()
+        .enumerate()
+        .map(|(i, t)| from_proto_arrow_type(Some(t), &format!("{field} {i}")))
+        .collect()
+}
+
+pub(crate) fn from_proto_volatility(v: i32) -> Result<Volatility> {
+    let pb = proto::Volatility::try_from(v)
+        .map_err(|_| anyhow!("invalid Volatility value: {v}"))?;
+
+    let df = match pb {
+        proto::Volatility::Immutable => Volatility::Immutable,
+        proto::Volatility::Stable => Volatility::Stable,
+        proto::Volatility::Volatile => Volatility::Volatile,
+    };
+
+    Ok(df)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proto_scalar(value: &ScalarValue) -> datafusion_proto::protobuf::ScalarValue {
+        value.try_into().unwrap()
+    }
+
+    #[test]
+    fn from_proto_sql_params_reads_positional_values_in_order() {
+        let params = proto::SqlParameters {
+            values: HashMap::new(),
+            positional_values: vec![proto_scalar(&ScalarValue::Int64(Some(1))), proto_scalar(&ScalarValue::from("a"))]
+        };
+
+        let ParamValues::List(values) = from_proto_sql_params(&params).unwrap() else { panic!("expected positional values") };
+
+        assert_eq!(values.iter().map(|v| v.value().clone()).collect::<Vec<_>>(),
+            vec![ScalarValue::Int64(Some(1)), ScalarValue::from("a")]);
+    }
+
+    #[test]
+    fn from_proto_sql_params_reads_named_values() {
+        let params = proto::SqlParameters {
+            values: HashMap::from([("id".to_string(), proto_scalar(&ScalarValue::Int32(Some(7))))]),
+            positional_values: Vec::new()
+        };
+
+        let ParamValues::Map(values) = from_proto_sql_params(&params).unwrap() else { panic!("expected named values") };
+
+        assert_eq!(values.get("id").map(|v| v.value().clone()), Some(ScalarValue::Int32(Some(7))));
+    }
+
+    #[test]
+    fn from_proto_sql_params_rejects_mixed_named_and_positional_values() {
+        let params = proto::SqlParameters {
+            values: HashMap::from([("id".to_string(), proto_scalar(&ScalarValue::Int32(Some(7))))]),
+            positional_values: vec![proto_scalar(&ScalarValue::Int64(Some(1)))]
+        };
+
+        let error = from_proto_sql_params(&params).unwrap_err();
+
+        assert!(error.to_string().contains("cannot be combined"), "{error}");
+    }
+
+    #[test]
+    fn to_proto_sql_parameter_types_sorts_positional_before_named() {
+        let types = HashMap::from([
+            ("$name".to_string(), Some(DataType::Utf8)),
+            ("$10".to_string(), None),
+            ("$2".to_string(), Some(DataType::Int64)),
+            ("$1".to_string(), Some(DataType::Boolean))
+        ]);
+
+        let parameters = to_proto_sql_parameter_types(types).unwrap().parameters;
+
+        assert_eq!(parameters.iter().map(|p| p.id.as_str()).collect::<Vec<_>>(), vec!["$1", "$2", "$10", "$name"]);
+        assert!(parameters[2].data_type.is_none());
+        assert_eq!(from_proto_arrow_type(parameters[1].data_type.as_ref(), "$2").unwrap(), DataType::Int64);
+    }
+}
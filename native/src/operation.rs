@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use futures::future::{AbortHandle, Abortable};
+
+use crate::{ErrorCode, ErrorInfo};
+
+/// Abort handles of in-flight async operations, keyed by their callback `user_data`.
+/// Each entry carries a sequence number so that a finished operation never removes a newer one with the same key.
+static OPERATIONS: LazyLock<Mutex<HashMap<u64, (u64, AbortHandle)>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+static NEXT_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// Spawns an async operation that can be cancelled with `datafusion_operation_cancel` using its `user_data`.
+///
+/// `operation` is responsible for invoking `callback` when it completes. If it is cancelled before completion,
+/// it is dropped together with any `DataFusion` streams it owns, and `callback` is invoked with `ErrorCode::Cancelled`
+/// instead, so the callback is always invoked exactly once.
+pub(crate) fn spawn_operation<F>(runtime: &crate::RuntimeHandle, callback: crate::Callback, user_data: u64, operation: F)
+where
+    F: Future<Output = ()> + Send + 'static
+{
+    spawn_operation_with_timeout(runtime, callback, user_data, 0, operation);
+}
+
+/// Spawns a cancellable async operation like `spawn_operation` with a deadline of `timeout_millis` (0 = no timeout).
+///
+/// If the deadline expires first, `operation` is dropped and `callback` is invoked with `ErrorCode::Timeout` instead.
+pub(crate) fn spawn_operation_with_timeout<F>(
+    runtime: &crate::RuntimeHandle,
+    callback: crate::Callback,
+    user_data: u64,
+    timeout_millis: u64,
+    operation: F
+)
+where
+    F: Future<Output = ()> + Send + 'static
+{
+    let operation = async move {
+        if timeout_millis == 0 {
+            operation.await;
+        } else if tokio::time::timeout(Duration::from_millis(timeout_millis), operation).await.is_err() {
+            dev_msg!("Operation {} timed out after {} ms", user_data, timeout_millis);
+            let error = ErrorInfo::new(ErrorCode::Timeout, format!("Operation timed out after {timeout_millis} ms"));
+            crate::invoke_callback_error(&error, callback, user_data);
+        }
+    };
+
+    let (abort_handle, abort_registration) = AbortHandle::new_pair();
+    let sequence = NEXT_SEQUENCE.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut operations) = OPERATIONS.lock() {
+        operations.insert(user_data, (sequence, abort_handle));
+    }
+
+    runtime.spawn(async move {
+        let result = Abortable::new(operation, abort_registration).await;
+
+        if let Ok(mut operations) = OPERATIONS.lock()
+            && operations.get(&user_data).is_some_and(|(s, _)| *s == sequence)
+        {
+            operations.remove(&user_data);
+        }
+
+        if result.is_err() {
+            dev_msg!("Operation {} was cancelled", user_data);
+            let error = ErrorInfo::new(ErrorCode::Cancelled, "Operation was cancelled");
+            crate::invoke_callback_error(&error, callback, user_data);
+        }
+    });
+}
+
+/// Cancels an in-flight async operation started with the given callback `user_data`.
+///
+/// The operation's callback is invoked with `ErrorCode::Cancelled` unless the operation has already completed,
+/// in which case this call has no effect. `user_data` must be unique among in-flight operations to be cancellable.
+#[unsafe(no_mangle)]
+pub extern "C" fn datafusion_operation_cancel(user_data: u64) -> ErrorCode {
+    dev_msg!("Cancelling operation {}", user_data);
+
+    let handle = OPERATIONS.lock()
+        .ok()
+        .and_then(|mut operations| operations.remove(&user_data));
+
+    if let Some((_, handle)) = handle {
+        handle.abort();
+    }
+
+    ErrorCode::Ok
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use datafusion::logical_expr::LogicalPlan;
+use datafusion::prelude::SessionContext;
+use prost::Message;
+
+use crate::{mappers, proto, ErrorCode, ErrorInfo, SessionContextWrapper};
+
+/// A planned SQL query whose placeholders are bound on every execution, without parsing or planning it again.
+pub struct PreparedStatementWrapper {
+    runtime: crate::RuntimeHandle,
+    context: Arc<SessionContext>,
+    plan: LogicalPlan
+}
+
+/// Parses and plans a SQL query once for repeated execution with different parameters.
+///
+/// Statements with side effects, such as DDL, run on every execution rather than when prepared.
+/// Statements rejected by the options fail with `ErrorCode::StatementNotAllowed` before the statement is created.
+///
+/// This is an async operation. The callback is invoked on completion with a `PreparedStatement` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `sql_ptr` must be a valid null-terminated UTF-8 string
+/// - `sql_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `SqlOptions`, or empty to allow all statements
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_prepared_statement_destroy` on the returned pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_prepare(
+    context_ptr: *mut SessionContextWrapper,
+    sql_ptr: *const std::ffi::c_char,
+    sql_options_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let sql = ffi_cstr_to_string!(sql_ptr);
+
+    let Ok((sql_options, dialect)) = crate::context::decode_sql_options(&sql_options_bytes) else { return ErrorCode::InvalidArgument };
+
+    dev_msg!("Preparing SQL query: {}", sql);
+
+    crate::spawn_operation(context.runtime(), callback, user_data, async move {
+        let result = context
+            .plan_sql_with_options(&sql, sql_options.as_ref(), dialect.as_deref())
+            .await
+            .map(|plan| Box::into_raw(Box::new(PreparedStatementWrapper {
+                runtime: Arc::clone(context.runtime()),
+                context: context.shared_inner(),
+                plan
+            })));
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Destroys a `PreparedStatement` created by `datafusion_context_prepare`.
+///
+/// # Safety
+/// - `statement_ptr` must be a valid pointer returned by `datafusion_context_prepare`, or null
+/// - Caller must not use `statement_ptr` after this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_prepared_statement_destroy(statement_ptr: *mut PreparedStatementWrapper) -> ErrorCode {
+    dev_msg!("Destroying prepared statement: {:p}", statement_ptr);
+
+    if !statement_ptr.is_null() {
+        unsafe { drop(Box::from_raw(statement_ptr)) };
+    }
+
+    ErrorCode::Ok
+}
+
+/// Binds parameters to a prepared statement and returns the result as a new `DataFrame`.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `statement_ptr` must be a valid pointer returned by `datafusion_context_prepare`
+/// - `sql_parameters_bytes` must be a valid `BytesData` containing a protobuf-encoded `SqlParameters`, or empty
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_prepared_statement_execute(
+    statement_ptr: *mut PreparedStatementWrapper,
+    sql_parameters_bytes: crate::BytesData,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let statement = ffi_ref!(statement_ptr);
+
+    let Ok(sql_parameters_proto) = sql_parameters_bytes.as_opt_slice()
+        .map(proto::SqlParameters::decode).transpose() else { return ErrorCode::InvalidArgument };
+    let Ok(sql_parameters) = sql_parameters_proto.as_ref()
+        .map(mappers::from_proto_sql_params).transpose() else { return ErrorCode::InvalidArgument };
+
+    dev_msg!("Executing prepared statement: {:p}", statement_ptr);
+
+    crate::spawn_operation(&statement.runtime, callback, user_data, async move {
+        let plan = match sql_parameters {
+            Some(p) => statement.plan.clone().with_param_values(p),
+            _ => Ok(statement.plan.clone())
+        };
+
+        let df = match plan {
+            Ok(plan) => statement.context.execute_logical_plan(plan).await,
+            Err(e) => Err(e)
+        };
+
+        let result = df
+            .map(|df| Box::into_raw(Box::new(crate::DataFrameWrapper::new(Arc::clone(&statement.runtime), df))))
+            .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e));
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Returns the inferred types of the placeholders of a prepared statement.
+///
+/// This is a synchronous operation. The callback is invoked immediately with a protobuf-encoded `SqlParameterTypes` as bytes.
+///
+/// # Safety
+/// - `statement_ptr` must be a valid pointer returned by `datafusion_context_prepare`
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_prepared_statement_parameter_types(
+    statement_ptr: *mut PreparedStatementWrapper,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let statement = ffi_ref!(statement_ptr);
+
+    let result = statement.plan
+        .get_parameter_types()
+        .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e))
+        .and_then(|types| mappers::to_proto_sql_parameter_types(types)
+            .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, e)));
+
+    crate::invoke_callback_message(result, callback, user_data);
+
+    ErrorCode::Ok
+}
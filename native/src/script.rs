@@ -0,0 +1,220 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use datafusion::common::config::Dialect;
+use datafusion::prelude::DataFrame;
+use datafusion::sql::parser::{DFParser, Statement};
+use datafusion::sql::sqlparser::dialect::{dialect_from_str, Dialect as ParserDialect};
+use datafusion::sql::sqlparser::tokenizer::{Location, Token, Tokenizer};
+use futures::StreamExt;
+
+use crate::{ErrorCode, ErrorInfo, SessionContextWrapper};
+
+/// A statement of a SQL script with its position in the script.
+struct ScriptStatement {
+    range: Range<usize>,
+    location: Location
+}
+
+/// Executes a script of SQL statements separated by semicolons and returns the `DataFrame` of the last statement.
+///
+/// All statements are parsed up front and then run in order; every statement but the last is fully executed,
+/// so `INSERT`s take effect, while its results are discarded as they are produced.
+/// Execution stops at the first failing statement, whose error code is reported with a message naming
+/// the statement's 1-based index, line and column. `SqlOptions` restrictions and dialect apply to every statement.
+///
+/// This is an async operation. The callback is invoked on completion with a `DataFrame` pointer.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `sql_ptr` must be a valid null-terminated UTF-8 string
+/// - `sql_options_bytes` must be a valid `BytesData` containing a protobuf-encoded `SqlOptions`, or empty to allow all statements
+/// - `callback` must be valid to call from any thread
+/// - Caller must call `datafusion_dataframe_destroy` on the returned `DataFrame` pointer
+///
+/// # Parameters
+/// - `timeout_millis`: Deadline for the whole script in milliseconds (0 = no timeout); on expiry the callback
+///   receives `ErrorCode::Timeout`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_sql_script(
+    context_ptr: *mut SessionContextWrapper,
+    sql_ptr: *const std::ffi::c_char,
+    sql_options_bytes: crate::BytesData,
+    timeout_millis: u64,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let sql = ffi_cstr_to_string!(sql_ptr);
+
+    let Ok((sql_options, dialect)) = crate::context::decode_sql_options(&sql_options_bytes) else { return ErrorCode::InvalidArgument };
+
+    dev_msg!("Executing SQL script: {}", sql);
+
+    crate::spawn_operation_with_timeout(context.runtime(), callback, user_data, timeout_millis, async move {
+        let result = async {
+            let dialect = match dialect.as_deref() {
+                Some(name) => crate::context::parse_dialect(name)?,
+                None => context.inner().state().config().options().sql_parser.dialect
+            };
+            // Every statement is parsed before the first one runs, so a syntax error leaves the session untouched
+            let statements = parse_script(&sql, dialect)?;
+            let count = statements.len();
+
+            for (index, (location, statement)) in statements.into_iter().enumerate() {
+                let at_statement = |e: ErrorInfo| at_statement(index, location, &e);
+
+                let plan = context.plan_statement(statement).await.map_err(at_statement)?;
+                let df = context.run_plan(plan, sql_options.as_ref()).await.map_err(at_statement)?;
+
+                if index + 1 == count {
+                    return Ok(Box::into_raw(Box::new(crate::DataFrameWrapper::new(Arc::clone(context.runtime()), df))));
+                }
+
+                drain(df)
+                    .await
+                    .map_err(|e| at_statement(ErrorInfo::from_datafusion(ErrorCode::SqlError, e)))?;
+            }
+
+            Err(ErrorInfo::new(ErrorCode::InvalidArgument, "SQL script contains no statements"))
+        }.await;
+
+        dev_msg!("Finished executing SQL script, dataframe ptr: {:p}", result.as_ref().ok().map_or(std::ptr::null(), |ptr| *ptr));
+
+        crate::invoke_callback(result, callback, user_data);
+    });
+
+    ErrorCode::Ok
+}
+
+/// Parses every statement of a script with `dialect`, keeping the position of each statement for error messages.
+fn parse_script(sql: &str, dialect: Dialect) -> Result<Vec<(Location, Statement)>, ErrorInfo> {
+    let parser_dialect = dialect_from_str(dialect)
+        .ok_or_else(|| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Unsupported SQL dialect: {dialect}")))?;
+
+    let mut statements = Vec::new();
+
+    for (index, statement) in split_statements(sql, parser_dialect.as_ref())?.into_iter().enumerate() {
+        let parsed = DFParser::parse_sql_with_dialect(&sql[statement.range], parser_dialect.as_ref())
+            .map_err(|e| at_statement(index, statement.location, &ErrorInfo::new(
+                ErrorCode::SqlError, format!("Failed to parse SQL with dialect '{dialect}': {e}"))))?;
+
+        statements.extend(parsed.into_iter().map(|parsed| (statement.location, parsed)));
+    }
+
+    Ok(statements)
+}
+
+/// Prefixes an error with the 1-based index and the position of the statement that caused it.
+fn at_statement(index: usize, location: Location, e: &ErrorInfo) -> ErrorInfo {
+    ErrorInfo::new(e.code(), format!(
+        "Statement {} at line {}, column {} failed: {}", index + 1, location.line, location.column, e.message()))
+}
+
+/// Executes a `DataFrame` for its side effects, discarding the produced batches as they arrive.
+async fn drain(df: DataFrame) -> datafusion::error::Result<()> {
+    let mut stream = df.execute_stream().await?;
+    while let Some(batch) = stream.next().await {
+        batch?;
+    }
+    Ok(())
+}
+
+/// Splits a script into statements at top-level semicolons using the tokenizer of `dialect`,
+/// so semicolons inside literals, quoted identifiers and comments are ignored. Empty statements are skipped.
+fn split_statements(sql: &str, dialect: &dyn ParserDialect) -> Result<Vec<ScriptStatement>, ErrorInfo> {
+    let tokens = Tokenizer::new(dialect, sql)
+        .tokenize_with_location()
+        .map_err(|e| ErrorInfo::new(ErrorCode::SqlError, format!("Failed to tokenize SQL script: {e}")))?;
+
+    let line_offsets = std::iter::once(0)
+        .chain(sql.match_indices('\n').map(|(i, _)| i + 1))
+        .collect::<Vec<_>>();
+    let offset = |location: Location| -> usize {
+        let line = usize::try_from(location.line.saturating_sub(1)).unwrap_or(usize::MAX);
+        let column = usize::try_from(location.column.saturating_sub(1)).unwrap_or(usize::MAX);
+        let line_start = line_offsets.get(line).copied().unwrap_or(sql.len());
+        sql[line_start..].char_indices()
+            .nth(column)
+            .map_or(sql.len(), |(i, _)| line_start + i)
+    };
+
+    let mut statements = Vec::new();
+    let mut start: Option<Location> = None;
+
+    for token in tokens {
+        match token.token {
+            Token::SemiColon => {
+                if let Some(location) = start.take() {
+                    statements.push(ScriptStatement { range: offset(location)..offset(token.span.start), location });
+                }
+            }
+            Token::Whitespace(_) => {}
+            _ => {
+                start.get_or_insert(token.span.start);
+            }
+        }
+    }
+
+    if let Some(location) = start {
+        statements.push(ScriptStatement { range: offset(location)..sql.len(), location });
+    }
+
+    Ok(statements)
+}
+
+#[cfg(test)]
+mod tests {
+    use datafusion::sql::sqlparser::dialect::GenericDialect;
+
+    use super::*;
+
+    fn split(sql: &str) -> Vec<&str> {
+        split_statements(sql, &GenericDialect {}).unwrap()
+            .into_iter()
+            .map(|statement| sql[statement.range].trim_end())
+            .collect()
+    }
+
+    #[test]
+    fn split_statements_ignores_semicolons_in_string_literals() {
+        assert_eq!(split("SELECT 'a;b'; SELECT \"c;d\" FROM t"), vec!["SELECT 'a;b'", "SELECT \"c;d\" FROM t"]);
+    }
+
+    #[test]
+    fn split_statements_ignores_semicolons_in_comments() {
+        let sql = "-- first; still a comment\nSELECT 1; /* block; comment */ SELECT 2";
+
+        assert_eq!(split(sql), vec!["SELECT 1", "SELECT 2"]);
+    }
+
+    #[test]
+    fn split_statements_skips_trailing_and_repeated_semicolons() {
+        assert_eq!(split("SELECT 1;; SELECT 2;\n"), vec!["SELECT 1", "SELECT 2"]);
+    }
+
+    #[test]
+    fn split_statements_returns_nothing_for_empty_script() {
+        assert!(split("").is_empty());
+        assert!(split(" ;\n-- only a comment\n").is_empty());
+    }
+
+    #[test]
+    fn split_statements_reports_statement_locations() {
+        let sql = "SELECT 1;\n  SELECT 'ä'; SELECT 3";
+        let locations = split_statements(sql, &GenericDialect {}).unwrap()
+            .into_iter()
+            .map(|statement| (statement.location.line, statement.location.column))
+            .collect::<Vec<_>>();
+
+        assert_eq!(locations, vec![(1, 1), (2, 3), (2, 15)]);
+    }
+
+    #[test]
+    fn parse_script_reports_the_failing_statement() {
+        let error = parse_script("SELECT 1;\nSELEC 2", Dialect::Generic).unwrap_err();
+
+        assert_eq!(error.code(), ErrorCode::SqlError);
+        assert!(error.message().starts_with("Statement 2 at line 2, column 1 failed"), "{}", error.message());
+    }
+}
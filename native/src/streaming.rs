@@ -0,0 +1,172 @@
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+use arrow_array::ffi_stream::ArrowArrayStreamReader;
+use arrow_array::RecordBatch;
+use async_trait::async_trait;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::catalog::{Session, TableProvider};
+use datafusion::datasource::TableType;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::logical_expr::Expr;
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::{PartitionStream, StreamingTableExec};
+use futures::Stream;
+
+use crate::{ErrorCode, ErrorInfo};
+
+/// A table backed by an Arrow C stream supplied by the caller.
+///
+/// The stream can only be read once, so the first scan claims it. Planning any later scan fails
+/// with `ErrorCode::InvalidArgument` instead of producing a plan that would fail during execution.
+pub(crate) struct ArrowStreamTable {
+    name: String,
+    schema: SchemaRef,
+    reader: Mutex<Option<ArrowArrayStreamReader>>
+}
+
+impl ArrowStreamTable {
+    pub(crate) fn new(name: &str, reader: ArrowArrayStreamReader) -> Self {
+        use datafusion::arrow::record_batch::RecordBatchReader;
+
+        Self {
+            name: name.to_string(),
+            schema: reader.schema(),
+            reader: Mutex::new(Some(reader))
+        }
+    }
+}
+
+impl std::fmt::Debug for ArrowStreamTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrowStreamTable")
+            .field("name", &self.name)
+            .field("schema", &self.schema)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl TableProvider for ArrowStreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::View
+    }
+
+    async fn scan(
+        &self,
+        _state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        limit: Option<usize>
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let reader = self.reader.lock()
+            .map_err(|_| DataFusionError::Internal("Arrow stream lock poisoned".to_string()))?
+            .take()
+            .ok_or_else(|| {
+                let message = format!("Arrow stream table '{}' has already been scanned; a stream can only be read by one query", self.name);
+                DataFusionError::External(Box::new(ErrorInfo::new(ErrorCode::InvalidArgument, message)))
+            })?;
+
+        let partition = ArrowStreamPartition {
+            schema: Arc::clone(&self.schema),
+            reader: Mutex::new(Some(reader))
+        };
+
+        let exec = StreamingTableExec::try_new(Arc::clone(&self.schema), vec![Arc::new(partition)], projection, std::iter::empty(), false, limit)?;
+        Ok(Arc::new(exec))
+    }
+}
+
+/// A single-use partition that pulls batches from a claimed Arrow C stream.
+///
+/// Batches are pulled lazily on a blocking thread because the producer may block while it waits for data.
+struct ArrowStreamPartition {
+    schema: SchemaRef,
+    reader: Mutex<Option<ArrowArrayStreamReader>>
+}
+
+impl std::fmt::Debug for ArrowStreamPartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrowStreamPartition")
+            .field("schema", &self.schema)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartitionStream for ArrowStreamPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let schema = Arc::clone(&self.schema);
+        let reader = self.reader.lock().ok().and_then(|mut r| r.take());
+
+        let Some(reader) = reader else {
+            let stream = futures::stream::once(async {
+                Err(DataFusionError::Execution("Arrow stream has already been consumed".to_string()))
+            });
+            return Box::pin(RecordBatchStreamAdapter::new(schema, stream));
+        };
+
+        Box::pin(RecordBatchStreamAdapter::new(schema, read_batches(reader)))
+    }
+}
+
+/// Pulls the batches of an Arrow C stream lazily, each on a blocking thread.
+pub(crate) fn read_batches(reader: ArrowArrayStreamReader) -> impl Stream<Item = Result<RecordBatch>> + Send {
+    futures::stream::try_unfold(reader, |mut reader| async move {
+        tokio::task::spawn_blocking(move || match reader.next() {
+            Some(Ok(batch)) => Ok(Some((batch, reader))),
+            Some(Err(e)) => Err(DataFusionError::from(e)),
+            None => Ok(None)
+        })
+        .await
+        .map_err(|e| DataFusionError::External(Box::new(e)))
+        .and_then(|r| r)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::ffi_stream::FFI_ArrowArrayStream;
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch, RecordBatchIterator};
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+
+    fn stream_table(values: Vec<i64>) -> ArrowStreamTable {
+        let batch = RecordBatch::try_from_iter([("id", Arc::new(Int64Array::from(values)) as ArrayRef)]).unwrap();
+        let batches = RecordBatchIterator::new([Ok(batch.clone())], batch.schema());
+        let reader = ArrowArrayStreamReader::try_new(FFI_ArrowArrayStream::new(Box::new(batches))).unwrap();
+
+        ArrowStreamTable::new("numbers", reader)
+    }
+
+    #[test]
+    fn second_scan_fails_with_invalid_argument() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let ctx = SessionContext::new();
+            ctx.register_table("numbers", Arc::new(stream_table(vec![1, 2, 3]))).unwrap();
+
+            let first = ctx.sql("SELECT sum(id) FROM numbers").await.unwrap().collect().await.unwrap();
+            let second = ctx.sql("SELECT sum(id) FROM numbers").await.unwrap().create_physical_plan().await.unwrap_err();
+
+            assert_eq!(first[0].num_rows(), 1);
+            let error = ErrorInfo::from_datafusion(ErrorCode::DataFrameError, second);
+            assert_eq!(error.code(), ErrorCode::InvalidArgument);
+            assert!(error.message().contains("'numbers' has already been scanned"));
+        });
+    }
+}
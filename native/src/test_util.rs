@@ -0,0 +1,47 @@
+use std::ffi::c_void;
+
+use crate::{BytesData, Callback, ErrorCode, ErrorInfo, ErrorInfoData};
+
+/// Outcome of a call in progress, passed to `record` through the callback's `user_data`.
+struct Call<T, R> {
+    read: Option<R>,
+    outcome: Option<Result<T, ErrorInfo>>
+}
+
+/// Records the outcome of a call, reading a successful result with the reader of the call.
+unsafe extern "C" fn record<T, R: FnOnce(*const c_void) -> T>(result: *const c_void, error: *const ErrorInfoData, user_data: u64) {
+    let call = unsafe { &mut *(user_data as *mut Call<T, R>) };
+
+    let outcome = if let Some(error) = unsafe { error.as_ref() } {
+        Err(ErrorInfo::new(error.code, String::from_utf8_lossy(error.message.as_opt_slice().unwrap_or_default())))
+    } else {
+        let read = call.read.take().expect("callback was invoked more than once");
+        Ok(read(result))
+    };
+
+    call.outcome = Some(outcome);
+}
+
+/// Calls a synchronous FFI operation and returns the outcome it reports to its callback,
+/// reading a successful result with `read`.
+///
+/// Panics if the operation fails to start or returns before invoking the callback.
+pub(crate) fn call<T, R: FnOnce(*const c_void) -> T>(read: R, operation: impl FnOnce(Callback, u64) -> ErrorCode) -> Result<T, ErrorInfo> {
+    let mut call = Call { read: Some(read), outcome: None };
+
+    assert_eq!(operation(record::<T, R>, &raw mut call as u64), ErrorCode::Ok);
+    call.outcome.expect("callback was not invoked")
+}
+
+/// Reads an operation result without data.
+pub(crate) fn unit(_result: *const c_void) {}
+
+/// Reads an operation result passed as `BytesData`.
+pub(crate) fn bytes(result: *const c_void) -> Vec<u8> {
+    unsafe { &*result.cast::<BytesData>() }.as_opt_slice().unwrap_or_default().to_vec()
+}
+
+/// Reads an operation result passed as `bool`.
+pub(crate) fn boolean(result: *const c_void) -> bool {
+    unsafe { *result.cast::<bool>() }
+}
@@ -0,0 +1,414 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::{Array, ArrayRef, StructArray};
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion::common::metadata::FieldMetadata;
+use datafusion::common::ScalarValue;
+use datafusion::error::{DataFusionError, Result as DFResult};
+use datafusion::logical_expr::function::{AccumulatorArgs, AggregateFunctionSimplification, StateFieldsArgs};
+use datafusion::logical_expr::utils::format_state_name;
+use datafusion::logical_expr::{Accumulator, AggregateUDF, AggregateUDFImpl, Expr, Signature, TypeSignature};
+use datafusion::logical_expr::lit_with_metadata;
+use prost::Message;
+
+use crate::udf::{self, ForeignHandle, ReleaseCallback};
+use crate::{mappers, proto, ErrorCode, ErrorInfo, SessionContextWrapper};
+
+/// Metadata key marking the placeholder argument added by `ForeignAggregateUdf::simplify`.
+const ROW_COUNT_PLACEHOLDER: &str = "datafusion_sharp.row_count_placeholder";
+
+/// Callback that feeds a batch of columns to an accumulator.
+///
+/// The columns are passed as a struct array with fields `arg0`, `arg1`, ...; the callee may move `args_array`
+/// and release `args_schema`, otherwise both are released after the callback returns.
+pub type AccumulatorBatchCallback = unsafe extern "C" fn(
+    args_array: *mut FFI_ArrowArray,
+    args_schema: *mut FFI_ArrowSchema,
+    error_message: *mut crate::BytesData,
+    accumulator: u64
+) -> ErrorCode;
+
+/// Callback that reads a value from an accumulator into a single-row array owned by the native side afterwards.
+pub type AccumulatorValueCallback = unsafe extern "C" fn(
+    result_array: *mut FFI_ArrowArray,
+    result_schema: *mut FFI_ArrowSchema,
+    error_message: *mut crate::BytesData,
+    accumulator: u64
+) -> ErrorCode;
+
+/// Callbacks implementing the accumulators of an aggregate UDF.
+///
+/// Every callback except `create` receives the accumulator id produced by `create`.
+/// Different accumulators may be used concurrently from multiple threads; a single accumulator is never used concurrently.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct AccumulatorCallbacks {
+    /// Creates a new accumulator for the function identified by `user_data` and writes its id to `accumulator`.
+    pub create: unsafe extern "C" fn(accumulator: *mut u64, error_message: *mut crate::BytesData, user_data: u64) -> ErrorCode,
+    /// Updates the accumulator with a batch of argument columns.
+    pub update_batch: AccumulatorBatchCallback,
+    /// Merges a batch of intermediate states, one column per state type, produced by other accumulators.
+    pub merge_batch: AccumulatorBatchCallback,
+    /// Returns the intermediate state as a single-row struct array with one column per state type.
+    pub state: AccumulatorValueCallback,
+    /// Returns the final value as a single-row array of the return type.
+    pub evaluate: AccumulatorValueCallback,
+    /// Frees an accumulator once it is no longer used.
+    pub release_accumulator: ReleaseCallback
+}
+
+/// Accumulator whose state lives on the caller side.
+#[derive(Debug)]
+struct ForeignAccumulator {
+    name: String,
+    return_type: DataType,
+    state_types: Vec<DataType>,
+    nullary: bool,
+    callbacks: AccumulatorCallbacks,
+    accumulator: u64
+}
+
+impl ForeignAccumulator {
+    fn call_batch(&self, callback: AccumulatorBatchCallback, columns: Vec<ArrayRef>, num_rows: usize) -> DFResult<()> {
+        let (mut args_array, mut args_schema) = udf::export_args(&self.name, columns, num_rows)?;
+        let mut error_message = crate::BytesData::empty();
+
+        let code = unsafe { callback(&raw mut args_array, &raw mut args_schema, &raw mut error_message, self.accumulator) };
+
+        udf::check_result_code(&self.name, code, &error_message)
+    }
+
+    fn call_value(&self, callback: AccumulatorValueCallback) -> DFResult<ArrayRef> {
+        let mut result_array = FFI_ArrowArray::empty();
+        let mut result_schema = FFI_ArrowSchema::empty();
+        let mut error_message = crate::BytesData::empty();
+
+        let code = unsafe { callback(&raw mut result_array, &raw mut result_schema, &raw mut error_message, self.accumulator) };
+
+        udf::check_result_code(&self.name, code, &error_message)?;
+        let result = udf::import_result(&self.name, result_array, &result_schema)?;
+
+        if result.len() != 1 {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned {} rows, expected 1", self.name, result.len())));
+        }
+
+        Ok(result)
+    }
+}
+
+impl Accumulator for ForeignAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> DFResult<()> {
+        let num_rows = values.first().map_or(0, Array::len);
+        // Only the row count of the placeholder argument added by `ForeignAggregateUdf::simplify` is passed on
+        let columns = if self.nullary { Vec::new() } else { values.to_vec() };
+        self.call_batch(self.callbacks.update_batch, columns, num_rows)
+    }
+
+    fn evaluate(&mut self) -> DFResult<ScalarValue> {
+        let result = self.call_value(self.callbacks.evaluate)?;
+        if result.data_type() != &self.return_type {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned a value of type {}, expected {}", self.name, result.data_type(), self.return_type)));
+        }
+
+        ScalarValue::try_from_array(&result, 0)
+    }
+
+    /// Returns the size of the native wrapper only; the memory used by the caller-side state is not tracked.
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+
+    fn state(&mut self) -> DFResult<Vec<ScalarValue>> {
+        let result = self.call_value(self.callbacks.state)?;
+        let Some(state) = result.as_any().downcast_ref::<StructArray>() else {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned {} as state, expected a struct", self.name, result.data_type())));
+        };
+
+        if state.num_columns() != self.state_types.len() {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned {} state columns, expected {}", self.name, state.num_columns(), self.state_types.len())));
+        }
+
+        if let Some((i, (column, expected))) = state.columns().iter().zip(&self.state_types).enumerate()
+            .find(|(_, (column, expected))| column.data_type() != *expected) {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned state column {i} of type {}, expected {expected}", self.name, column.data_type())));
+        }
+
+        state.columns().iter()
+            .map(|c| ScalarValue::try_from_array(c, 0))
+            .collect()
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> DFResult<()> {
+        self.call_batch(self.callbacks.merge_batch, states.to_vec(), states.first().map_or(0, Array::len))
+    }
+}
+
+impl Drop for ForeignAccumulator {
+    fn drop(&mut self) {
+        unsafe { (self.callbacks.release_accumulator)(self.accumulator) };
+    }
+}
+
+/// Aggregate UDF whose accumulators are implemented by caller-provided callbacks.
+#[derive(Debug)]
+pub(crate) struct ForeignAggregateUdf {
+    name: String,
+    signature: Signature,
+    return_type: DataType,
+    state_types: Vec<DataType>,
+    nullary: bool,
+    callbacks: AccumulatorCallbacks,
+    handle: ForeignHandle
+}
+
+impl PartialEq for ForeignAggregateUdf {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.signature == other.signature
+            && self.return_type == other.return_type
+            && self.state_types == other.state_types
+            && self.handle.user_data() == other.handle.user_data()
+    }
+}
+
+impl Eq for ForeignAggregateUdf {}
+
+impl Hash for ForeignAggregateUdf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.signature.hash(state);
+        self.return_type.hash(state);
+        self.state_types.hash(state);
+        self.handle.user_data().hash(state);
+    }
+}
+
+impl AggregateUDFImpl for ForeignAggregateUdf {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DFResult<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    /// Rejects arguments passed to functions without arguments, other than the placeholder added by `simplify`.
+    fn return_field(&self, arg_fields: &[FieldRef]) -> DFResult<FieldRef> {
+        if self.nullary && arg_fields.iter().any(|f| !f.metadata().contains_key(ROW_COUNT_PLACEHOLDER)) {
+            return Err(DataFusionError::Plan(format!("Function '{}' takes no arguments", self.name)));
+        }
+
+        Ok(Arc::new(Field::new(&self.name, self.return_type.clone(), true)))
+    }
+
+    fn accumulator(&self, _acc_args: AccumulatorArgs) -> DFResult<Box<dyn Accumulator>> {
+        let mut accumulator = 0;
+        let mut error_message = crate::BytesData::empty();
+
+        let code = unsafe { (self.callbacks.create)(&raw mut accumulator, &raw mut error_message, self.handle.user_data()) };
+        udf::check_result_code(&self.name, code, &error_message)?;
+
+        Ok(Box::new(ForeignAccumulator {
+            name: self.name.clone(),
+            return_type: self.return_type.clone(),
+            state_types: self.state_types.clone(),
+            nullary: self.nullary,
+            callbacks: self.callbacks,
+            accumulator
+        }))
+    }
+
+    /// Adds a placeholder argument to calls of functions without arguments.
+    ///
+    /// `DataFusion` passes no columns, and so no row count, to accumulators of such functions;
+    /// the accumulator takes the row count from the placeholder instead (much like `count(*)` becomes `count(1)`).
+    fn simplify(&self) -> Option<AggregateFunctionSimplification> {
+        if !self.nullary {
+            return None;
+        }
+
+        Some(Box::new(|mut function, _info| {
+            if function.params.args.is_empty() {
+                let metadata = BTreeMap::from([(ROW_COUNT_PLACEHOLDER.to_string(), String::new())]);
+                function.params.args.push(lit_with_metadata(true, Some(FieldMetadata::from(metadata))));
+            }
+            Ok(Expr::AggregateFunction(function))
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> DFResult<Vec<FieldRef>> {
+        Ok(self.state_types.iter()
+            .enumerate()
+            .map(|(i, t)| Arc::new(Field::new(format_state_name(args.name, &format!("state{i}")), t.clone(), true)))
+            .collect())
+    }
+}
+
+/// Registers an aggregate UDF whose accumulators are implemented by the caller.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `name_ptr` must be a valid null-terminated UTF-8 string
+/// - `signature_bytes` must be a valid `BytesData` containing a protobuf-encoded `AggregateUdfSignature`
+/// - `accumulator_callbacks_ptr` must be a valid pointer to `AccumulatorCallbacks`; the struct is copied
+/// - All accumulator callbacks must be valid to call from any thread for as long as the function is registered
+/// - `release`, if not null, is invoked with `function_user_data` once the function is dropped,
+///   including when registration fails after the pointers were validated
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_aggregate_udf(
+    context_ptr: *mut SessionContextWrapper,
+    name_ptr: *const std::ffi::c_char,
+    signature_bytes: crate::BytesData,
+    accumulator_callbacks_ptr: *const AccumulatorCallbacks,
+    release: Option<ReleaseCallback>,
+    function_user_data: u64,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let name = ffi_cstr_to_string!(name_ptr);
+    let callbacks = *ffi_ref!(accumulator_callbacks_ptr);
+    let handle = ForeignHandle::new(function_user_data, release);
+
+    dev_msg!("Registering aggregate UDF '{}'", name);
+
+    let result = decode_aggregate_udf_signature(&signature_bytes)
+        .map(|(signature, return_type, state_types, nullary)| {
+            let udaf = ForeignAggregateUdf { name, signature, return_type, state_types, nullary, callbacks, handle };
+            context.inner().register_udaf(AggregateUDF::new_from_impl(udaf));
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+/// Decodes an aggregate UDF signature, also returning whether the function takes no arguments.
+fn decode_aggregate_udf_signature(signature_bytes: &crate::BytesData) -> Result<(Signature, DataType, Vec<DataType>, bool), ErrorInfo> {
+    let bytes = signature_bytes.as_opt_slice()
+        .ok_or_else(|| ErrorInfo::new(ErrorCode::InvalidArgument, "Aggregate UDF signature is required"))?;
+
+    let signature_proto = proto::AggregateUdfSignature::decode(bytes)
+        .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to decode aggregate UDF signature: {e}")))?;
+
+    let convert = || -> anyhow::Result<(Signature, DataType, Vec<DataType>, bool)> {
+        let arg_types = mappers::from_proto_arrow_types(&signature_proto.arg_types, "argument")?;
+        let return_type = mappers::from_proto_arrow_type(signature_proto.return_type.as_ref(), "return value")?;
+        let state_types = mappers::from_proto_arrow_types(&signature_proto.state_types, "state")?;
+        let volatility = mappers::from_proto_volatility(signature_proto.volatility)?;
+
+        if state_types.is_empty() {
+            anyhow::bail!("At least one state type is required");
+        }
+
+        // Functions without arguments also accept the placeholder argument added by `ForeignAggregateUdf::simplify`;
+        // any other argument is rejected by `ForeignAggregateUdf::return_field`
+        let nullary = arg_types.is_empty();
+        let signature = if nullary {
+            Signature::one_of(vec![TypeSignature::Nullary, TypeSignature::Exact(vec![DataType::Boolean])], volatility)
+        } else {
+            Signature::exact(arg_types, volatility)
+        };
+
+        Ok((signature, return_type, state_types, nullary))
+    };
+
+    convert().map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to convert aggregate UDF signature: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::ffi::{from_ffi, to_ffi};
+    use arrow_array::{Int64Array, RecordBatch};
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+
+    /// Counts the rows passed to `update_batch`, keeping the count in a boxed `i64`.
+    unsafe extern "C" fn create(accumulator: *mut u64, _error_message: *mut crate::BytesData, _user_data: u64) -> ErrorCode {
+        unsafe { *accumulator = Box::into_raw(Box::new(0_i64)) as u64 };
+        ErrorCode::Ok
+    }
+
+    unsafe extern "C" fn update_batch(args_array: *mut FFI_ArrowArray, args_schema: *mut FFI_ArrowSchema, _error_message: *mut crate::BytesData, accumulator: u64) -> ErrorCode {
+        let args = unsafe { from_ffi(std::ptr::replace(args_array, FFI_ArrowArray::empty()), &*args_schema) }.unwrap();
+        unsafe { *(accumulator as *mut i64) += i64::try_from(args.len()).unwrap() };
+        ErrorCode::Ok
+    }
+
+    unsafe extern "C" fn merge_batch(args_array: *mut FFI_ArrowArray, args_schema: *mut FFI_ArrowSchema, _error_message: *mut crate::BytesData, accumulator: u64) -> ErrorCode {
+        let states = StructArray::from(unsafe { from_ffi(std::ptr::replace(args_array, FFI_ArrowArray::empty()), &*args_schema) }.unwrap());
+        let counts = states.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        unsafe { *(accumulator as *mut i64) += counts.iter().flatten().sum::<i64>() };
+        ErrorCode::Ok
+    }
+
+    unsafe extern "C" fn state(result_array: *mut FFI_ArrowArray, result_schema: *mut FFI_ArrowSchema, _error_message: *mut crate::BytesData, accumulator: u64) -> ErrorCode {
+        let count = Arc::new(Int64Array::from(vec![unsafe { *(accumulator as *mut i64) }])) as ArrayRef;
+        let state = StructArray::from(RecordBatch::try_from_iter([("count", count)]).unwrap());
+        unsafe { (*result_array, *result_schema) = to_ffi(&state.to_data()).unwrap() };
+        ErrorCode::Ok
+    }
+
+    unsafe extern "C" fn evaluate(result_array: *mut FFI_ArrowArray, result_schema: *mut FFI_ArrowSchema, _error_message: *mut crate::BytesData, accumulator: u64) -> ErrorCode {
+        let count = Int64Array::from(vec![unsafe { *(accumulator as *mut i64) }]);
+        unsafe { (*result_array, *result_schema) = to_ffi(&count.to_data()).unwrap() };
+        ErrorCode::Ok
+    }
+
+    unsafe extern "C" fn release_accumulator(accumulator: u64) {
+        drop(unsafe { Box::from_raw(accumulator as *mut i64) });
+    }
+
+    fn count_rows_udaf() -> AggregateUDF {
+        let int64: datafusion_proto::protobuf::ArrowType = (&DataType::Int64).try_into().unwrap();
+        let signature_proto = proto::AggregateUdfSignature {
+            return_type: Some(int64.clone()),
+            state_types: vec![int64],
+            ..Default::default()
+        };
+        let signature_bytes = signature_proto.encode_to_vec();
+
+        let (signature, return_type, state_types, nullary) = decode_aggregate_udf_signature(&crate::BytesData::new(&signature_bytes)).unwrap();
+        let callbacks = AccumulatorCallbacks { create, update_batch, merge_batch, state, evaluate, release_accumulator };
+        let handle = ForeignHandle::new(0, None);
+
+        AggregateUDF::new_from_impl(ForeignAggregateUdf { name: "count_rows".to_string(), signature, return_type, state_types, nullary, callbacks, handle })
+    }
+
+    #[test]
+    fn nullary_function_counts_rows_and_rejects_arguments() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let ctx = SessionContext::new();
+            ctx.register_udaf(count_rows_udaf());
+
+            let counted = ctx.sql("SELECT count_rows() FROM generate_series(1, 42)").await.unwrap().collect().await.unwrap();
+            let rejected = ctx.sql("SELECT count_rows(true) FROM generate_series(1, 42)").await.unwrap_err();
+
+            let count = counted[0].column(0).as_any().downcast_ref::<Int64Array>().unwrap().value(0);
+            assert_eq!(count, 42);
+            assert!(rejected.to_string().contains("Function 'count_rows' takes no arguments"), "{rejected}");
+        });
+    }
+}
@@ -0,0 +1,239 @@
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::{Array, ArrayRef};
+use datafusion::arrow::datatypes::DataType;
+use datafusion::error::{DataFusionError, Result as DFResult};
+use datafusion::logical_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl, Signature};
+use prost::Message;
+
+use crate::{arrow_ffi, mappers, proto, ErrorCode, ErrorInfo, SessionContextWrapper};
+
+/// Callback invoked when a caller-implemented function is dropped, so the caller can free its state.
+pub type ReleaseCallback = unsafe extern "C" fn(user_data: u64);
+
+/// Callback implemented by the caller that maps input columns to a result array.
+///
+/// - `args_array`/`args_schema`: the input columns as a struct array with fields `arg0`, `arg1`, ...;
+///   the callee may move `args_array` and release `args_schema`, otherwise both are released after the callback returns
+/// - `result_array`/`result_schema`: output; the callee must fill both on success, ownership is transferred to the native side
+/// - `error_message`: output; on failure the callee may point it to a UTF-8 message that stays valid after the callback
+///   returns, until the next callback on the same thread
+///
+/// Returns `ErrorCode::Ok` on success. May be called concurrently from multiple threads.
+pub type ArrayFunctionCallback = unsafe extern "C" fn(
+    args_array: *mut FFI_ArrowArray,
+    args_schema: *mut FFI_ArrowSchema,
+    result_array: *mut FFI_ArrowArray,
+    result_schema: *mut FFI_ArrowSchema,
+    error_message: *mut crate::BytesData,
+    user_data: u64
+) -> ErrorCode;
+
+/// Caller-owned state of a foreign function, released when the function is dropped.
+#[derive(Debug)]
+pub(crate) struct ForeignHandle {
+    user_data: u64,
+    release: Option<ReleaseCallback>
+}
+
+impl ForeignHandle {
+    pub(crate) fn new(user_data: u64, release: Option<ReleaseCallback>) -> Self {
+        Self { user_data, release }
+    }
+
+    pub(crate) fn user_data(&self) -> u64 {
+        self.user_data
+    }
+}
+
+impl Drop for ForeignHandle {
+    fn drop(&mut self) {
+        dev_msg!("Releasing foreign function state: {}", self.user_data);
+
+        if let Some(release) = self.release {
+            unsafe { release(self.user_data) };
+        }
+    }
+}
+
+/// Invokes an `ArrayFunctionCallback` with the given columns and imports the returned array.
+pub(crate) fn invoke_array_function(
+    function_name: &str,
+    callback: ArrayFunctionCallback,
+    user_data: u64,
+    columns: Vec<ArrayRef>,
+    num_rows: usize
+) -> DFResult<ArrayRef> {
+    let (mut args_array, mut args_schema) = export_args(function_name, columns, num_rows)?;
+
+    let mut result_array = FFI_ArrowArray::empty();
+    let mut result_schema = FFI_ArrowSchema::empty();
+    let mut error_message = crate::BytesData::empty();
+
+    let code = unsafe {
+        callback(
+            &raw mut args_array,
+            &raw mut args_schema,
+            &raw mut result_array,
+            &raw mut result_schema,
+            &raw mut error_message,
+            user_data
+        )
+    };
+
+    check_result_code(function_name, code, &error_message)?;
+    import_result(function_name, result_array, &result_schema)
+}
+
+/// Exports function arguments as a struct array for a foreign callback.
+pub(crate) fn export_args(function_name: &str, columns: Vec<ArrayRef>, num_rows: usize) -> DFResult<(FFI_ArrowArray, FFI_ArrowSchema)> {
+    arrow_ffi::export_columns(columns, num_rows)
+        .map_err(|e| DataFusionError::Execution(format!("Function '{function_name}': {e}")))
+}
+
+/// Converts the status returned by a foreign callback into a `DataFusionError` carrying the callee's message.
+pub(crate) fn check_result_code(function_name: &str, code: ErrorCode, error_message: &crate::BytesData) -> DFResult<()> {
+    if code == ErrorCode::Ok {
+        return Ok(());
+    }
+
+    let message = error_message.as_opt_slice().map(String::from_utf8_lossy).unwrap_or_default();
+    Err(DataFusionError::Execution(format!("Function '{function_name}' failed with {code:?}: {message}")))
+}
+
+/// Imports an array returned by a foreign callback.
+pub(crate) fn import_result(function_name: &str, result_array: FFI_ArrowArray, result_schema: &FFI_ArrowSchema) -> DFResult<ArrayRef> {
+    arrow_ffi::import_array(result_array, result_schema)
+        .map_err(|e| DataFusionError::Execution(format!("Function '{function_name}': {e}")))
+}
+
+/// Scalar UDF whose invocation is delegated to a caller-provided callback.
+#[derive(Debug)]
+pub(crate) struct ForeignScalarUdf {
+    name: String,
+    signature: Signature,
+    return_type: DataType,
+    invoke: ArrayFunctionCallback,
+    handle: ForeignHandle
+}
+
+impl PartialEq for ForeignScalarUdf {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.signature == other.signature
+            && self.return_type == other.return_type
+            && self.handle.user_data() == other.handle.user_data()
+    }
+}
+
+impl Eq for ForeignScalarUdf {}
+
+impl Hash for ForeignScalarUdf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.signature.hash(state);
+        self.return_type.hash(state);
+        self.handle.user_data().hash(state);
+    }
+}
+
+impl ScalarUDFImpl for ForeignScalarUdf {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> DFResult<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> DFResult<ColumnarValue> {
+        let num_rows = args.number_rows;
+        let columns = args.args.into_iter()
+            .map(|arg| arg.into_array(num_rows))
+            .collect::<DFResult<Vec<_>>>()?;
+
+        let result = invoke_array_function(&self.name, self.invoke, self.handle.user_data(), columns, num_rows)?;
+
+        if result.len() != num_rows {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned {} rows, expected {num_rows}", self.name, result.len())));
+        }
+        if result.data_type() != &self.return_type {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned {}, expected {}", self.name, result.data_type(), self.return_type)));
+        }
+
+        Ok(ColumnarValue::Array(result))
+    }
+}
+
+/// Registers a scalar UDF implemented by the caller.
+///
+/// Each invocation of the function passes the argument columns to `invoke` and expects an array of the
+/// declared return type with the same number of rows back.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `name_ptr` must be a valid null-terminated UTF-8 string
+/// - `signature_bytes` must be a valid `BytesData` containing a protobuf-encoded `ScalarUdfSignature`
+/// - `invoke` must be valid to call from any thread for as long as the function is registered
+/// - `release`, if not null, is invoked with `function_user_data` once the function is dropped,
+///   including when registration fails after the pointers were validated
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_scalar_udf(
+    context_ptr: *mut SessionContextWrapper,
+    name_ptr: *const std::ffi::c_char,
+    signature_bytes: crate::BytesData,
+    invoke: ArrayFunctionCallback,
+    release: Option<ReleaseCallback>,
+    function_user_data: u64,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let name = ffi_cstr_to_string!(name_ptr);
+    let handle = ForeignHandle::new(function_user_data, release);
+
+    dev_msg!("Registering scalar UDF '{}'", name);
+
+    let result = decode_scalar_udf_signature(&signature_bytes)
+        .map(|(signature, return_type)| {
+            let udf = ForeignScalarUdf { name, signature, return_type, invoke, handle };
+            context.inner().register_udf(ScalarUDF::new_from_impl(udf));
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+fn decode_scalar_udf_signature(signature_bytes: &crate::BytesData) -> Result<(Signature, DataType), ErrorInfo> {
+    let bytes = signature_bytes.as_opt_slice()
+        .ok_or_else(|| ErrorInfo::new(ErrorCode::InvalidArgument, "Scalar UDF signature is required"))?;
+
+    let signature_proto = proto::ScalarUdfSignature::decode(bytes)
+        .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to decode scalar UDF signature: {e}")))?;
+
+    let convert = || -> anyhow::Result<(Signature, DataType)> {
+        let arg_types = mappers::from_proto_arrow_types(&signature_proto.arg_types, "argument")?;
+        let return_type = mappers::from_proto_arrow_type(signature_proto.return_type.as_ref(), "return value")?;
+        let volatility = mappers::from_proto_volatility(signature_proto.volatility)?;
+
+        Ok((Signature::exact(arg_types, volatility), return_type))
+    };
+
+    convert().map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to convert scalar UDF signature: {e}")))
+}
@@ -0,0 +1,196 @@
+use std::sync::{Arc, Mutex};
+
+use arrow_array::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow_array::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow_array::ArrayRef;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::record_batch::RecordBatchReader;
+use datafusion::catalog::streaming::StreamingTable;
+use datafusion::catalog::{TableFunctionImpl, TableProvider};
+use datafusion::error::{DataFusionError, Result as DFResult};
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::logical_expr::Expr;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream;
+
+use crate::udf::{self, ForeignHandle, ReleaseCallback};
+use crate::{streaming, ErrorCode, SessionContextWrapper};
+
+/// Callback implemented by the caller that produces the rows of a table function call.
+///
+/// - `args_array`/`args_schema`: the literal arguments as a single-row struct array with fields `arg0`, `arg1`, ...;
+///   the callee may move `args_array` and release `args_schema`, otherwise both are released after the callback returns
+/// - `stream_out`: output; the callee must fill it with an Arrow C stream on success, ownership is transferred to the native side
+/// - `error_message`: output; on failure the callee may point it to a UTF-8 message that stays valid after the callback
+///   returns, until the next callback on the same thread
+///
+/// Returns `ErrorCode::Ok` on success. Called once when a query referencing the function is planned and once more
+/// for every execution of that plan after the first, possibly from multiple threads.
+pub type TableFunctionCallback = unsafe extern "C" fn(
+    args_array: *mut FFI_ArrowArray,
+    args_schema: *mut FFI_ArrowSchema,
+    stream_out: *mut FFI_ArrowArrayStream,
+    error_message: *mut crate::BytesData,
+    user_data: u64
+) -> ErrorCode;
+
+/// Table function whose rows are streamed from a caller-provided callback.
+#[derive(Debug)]
+pub(crate) struct ForeignTableFunction {
+    name: String,
+    invoke: TableFunctionCallback,
+    handle: Arc<ForeignHandle>
+}
+
+impl ForeignTableFunction {
+    fn literal_args(&self, args: &[Expr]) -> DFResult<Vec<ArrayRef>> {
+        args.iter()
+            .enumerate()
+            .map(|(i, arg)| match arg {
+                Expr::Literal(value, _) => value.to_array_of_size(1),
+                other => Err(DataFusionError::Plan(format!(
+                    "Table function '{}' only accepts literal arguments, argument {i} is '{other}'", self.name)))
+            })
+            .collect()
+    }
+}
+
+impl TableFunctionImpl for ForeignTableFunction {
+    fn call(&self, args: &[Expr]) -> DFResult<Arc<dyn TableProvider>> {
+        let call = TableFunctionCall {
+            name: self.name.clone(),
+            invoke: self.invoke,
+            handle: Arc::clone(&self.handle),
+            args: self.literal_args(args)?
+        };
+
+        // The schema is only known once the callback returned a stream, which is kept for the first execution
+        let reader = call.invoke()?;
+        let partition = Arc::new(TableFunctionPartition {
+            call,
+            schema: reader.schema(),
+            planned: Mutex::new(Some(reader))
+        });
+
+        Ok(Arc::new(StreamingTable::try_new(Arc::clone(&partition.schema), vec![partition])?))
+    }
+}
+
+/// A table function invocation with its literal arguments.
+#[derive(Debug)]
+struct TableFunctionCall {
+    name: String,
+    invoke: TableFunctionCallback,
+    handle: Arc<ForeignHandle>,
+    args: Vec<ArrayRef>
+}
+
+impl TableFunctionCall {
+    fn invoke(&self) -> DFResult<ArrowArrayStreamReader> {
+        let (mut args_array, mut args_schema) = udf::export_args(&self.name, self.args.clone(), 1)?;
+
+        let mut stream = FFI_ArrowArrayStream::empty();
+        let mut error_message = crate::BytesData::empty();
+
+        let code = unsafe {
+            (self.invoke)(
+                &raw mut args_array,
+                &raw mut args_schema,
+                &raw mut stream,
+                &raw mut error_message,
+                self.handle.user_data()
+            )
+        };
+
+        udf::check_result_code(&self.name, code, &error_message)?;
+
+        ArrowArrayStreamReader::try_new(stream)
+            .map_err(|e| DataFusionError::Execution(format!("Function '{}': failed to import Arrow stream: {e}", self.name)))
+    }
+}
+
+/// Partition of a table function call.
+///
+/// The stream produced while planning is consumed by the first execution;
+/// every further execution invokes the callback again with the same arguments.
+struct TableFunctionPartition {
+    call: TableFunctionCall,
+    schema: SchemaRef,
+    planned: Mutex<Option<ArrowArrayStreamReader>>
+}
+
+impl TableFunctionPartition {
+    fn invoke_again(&self) -> DFResult<ArrowArrayStreamReader> {
+        let reader = self.call.invoke()?;
+
+        if reader.schema() != self.schema {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned a stream with schema {:?}, expected {:?}", self.call.name, reader.schema(), self.schema)));
+        }
+
+        Ok(reader)
+    }
+}
+
+impl std::fmt::Debug for TableFunctionPartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableFunctionPartition")
+            .field("call", &self.call)
+            .field("schema", &self.schema)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartitionStream for TableFunctionPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let schema = Arc::clone(&self.schema);
+        let planned = self.planned.lock().ok().and_then(|mut r| r.take());
+
+        match planned.map_or_else(|| self.invoke_again(), Ok) {
+            Ok(reader) => Box::pin(RecordBatchStreamAdapter::new(schema, streaming::read_batches(reader))),
+            Err(e) => Box::pin(RecordBatchStreamAdapter::new(schema, futures::stream::once(async { Err(e) })))
+        }
+    }
+}
+
+/// Registers a table function implemented by the caller, usable as `SELECT * FROM name('x', 3)`.
+///
+/// Arguments must be literals. Every query referencing the function invokes `invoke` once while planning,
+/// and the returned Arrow stream is consumed when the query first executes; executing the same plan again
+/// (e.g. collecting a `DataFrame` twice) invokes `invoke` again and requires a stream with the same schema.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `name_ptr` must be a valid null-terminated UTF-8 string
+/// - `invoke` must be valid to call from any thread for as long as the function is registered
+/// - `release`, if not null, is invoked with `function_user_data` once the function is dropped
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_table_function(
+    context_ptr: *mut SessionContextWrapper,
+    name_ptr: *const std::ffi::c_char,
+    invoke: TableFunctionCallback,
+    release: Option<ReleaseCallback>,
+    function_user_data: u64,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let name = ffi_cstr_to_string!(name_ptr);
+    let handle = Arc::new(ForeignHandle::new(function_user_data, release));
+
+    dev_msg!("Registering table function '{}'", name);
+
+    let function = ForeignTableFunction { name: name.clone(), invoke, handle };
+    context.inner().register_udtf(&name, Arc::new(function));
+
+    crate::invoke_callback(Ok(()), callback, user_data);
+
+    ErrorCode::Ok
+}
@@ -0,0 +1,157 @@
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef};
+use datafusion::arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion::error::{DataFusionError, Result as DFResult};
+use datafusion::logical_expr::function::{PartitionEvaluatorArgs, WindowUDFFieldArgs};
+use datafusion::logical_expr::{PartitionEvaluator, Signature, WindowUDF, WindowUDFImpl};
+use prost::Message;
+
+use crate::udf::{self, ArrayFunctionCallback, ForeignHandle, ReleaseCallback};
+use crate::{mappers, proto, ErrorCode, ErrorInfo, SessionContextWrapper};
+
+/// Partition evaluator that passes the whole partition to a caller-provided callback.
+#[derive(Debug)]
+struct ForeignPartitionEvaluator {
+    name: String,
+    return_type: DataType,
+    evaluate: ArrayFunctionCallback,
+    handle: Arc<ForeignHandle>
+}
+
+impl PartitionEvaluator for ForeignPartitionEvaluator {
+    fn evaluate_all(&mut self, values: &[ArrayRef], num_rows: usize) -> DFResult<ArrayRef> {
+        let result = udf::invoke_array_function(&self.name, self.evaluate, self.handle.user_data(), values.to_vec(), num_rows)?;
+
+        if result.len() != num_rows {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned {} rows, expected {num_rows}", self.name, result.len())));
+        }
+        if result.data_type() != &self.return_type {
+            return Err(DataFusionError::Execution(format!(
+                "Function '{}' returned {}, expected {}", self.name, result.data_type(), self.return_type)));
+        }
+
+        Ok(result)
+    }
+}
+
+/// Window UDF evaluated by a caller-provided callback, one partition at a time.
+#[derive(Debug)]
+pub(crate) struct ForeignWindowUdf {
+    name: String,
+    signature: Signature,
+    return_type: DataType,
+    evaluate: ArrayFunctionCallback,
+    handle: Arc<ForeignHandle>
+}
+
+impl PartialEq for ForeignWindowUdf {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.signature == other.signature
+            && self.return_type == other.return_type
+            && self.handle.user_data() == other.handle.user_data()
+    }
+}
+
+impl Eq for ForeignWindowUdf {}
+
+impl Hash for ForeignWindowUdf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.signature.hash(state);
+        self.return_type.hash(state);
+        self.handle.user_data().hash(state);
+    }
+}
+
+impl WindowUDFImpl for ForeignWindowUdf {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn partition_evaluator(&self, _partition_evaluator_args: PartitionEvaluatorArgs) -> DFResult<Box<dyn PartitionEvaluator>> {
+        Ok(Box::new(ForeignPartitionEvaluator {
+            name: self.name.clone(),
+            return_type: self.return_type.clone(),
+            evaluate: self.evaluate,
+            handle: Arc::clone(&self.handle)
+        }))
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> DFResult<FieldRef> {
+        Ok(Arc::new(Field::new(field_args.name(), self.return_type.clone(), true)))
+    }
+}
+
+/// Registers a window UDF implemented by the caller.
+///
+/// For every window partition, `evaluate` receives all argument rows of the partition and must return an array
+/// of the declared return type with one value per row. Window frames are not passed to the callback.
+///
+/// This is a synchronous operation. The callback is invoked immediately with no result data.
+///
+/// # Safety
+/// - `context_ptr` must be a valid pointer returned by `datafusion_context_new`
+/// - `name_ptr` must be a valid null-terminated UTF-8 string
+/// - `signature_bytes` must be a valid `BytesData` containing a protobuf-encoded `WindowUdfSignature`
+/// - `evaluate` must be valid to call from any thread for as long as the function is registered
+/// - `release`, if not null, is invoked with `function_user_data` once the function is dropped,
+///   including when registration fails after the pointers were validated
+/// - `callback` must be valid to call from the current thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn datafusion_context_register_window_udf(
+    context_ptr: *mut SessionContextWrapper,
+    name_ptr: *const std::ffi::c_char,
+    signature_bytes: crate::BytesData,
+    evaluate: ArrayFunctionCallback,
+    release: Option<ReleaseCallback>,
+    function_user_data: u64,
+    callback: crate::Callback,
+    user_data: u64
+) -> ErrorCode {
+    let context = ffi_ref!(context_ptr);
+    let name = ffi_cstr_to_string!(name_ptr);
+    let handle = Arc::new(ForeignHandle::new(function_user_data, release));
+
+    dev_msg!("Registering window UDF '{}'", name);
+
+    let result = decode_window_udf_signature(&signature_bytes)
+        .map(|(signature, return_type)| {
+            let udwf = ForeignWindowUdf { name, signature, return_type, evaluate, handle };
+            context.inner().register_udwf(WindowUDF::new_from_impl(udwf));
+        });
+
+    crate::invoke_callback(result, callback, user_data);
+
+    ErrorCode::Ok
+}
+
+fn decode_window_udf_signature(signature_bytes: &crate::BytesData) -> Result<(Signature, DataType), ErrorInfo> {
+    let bytes = signature_bytes.as_opt_slice()
+        .ok_or_else(|| ErrorInfo::new(ErrorCode::InvalidArgument, "Window UDF signature is required"))?;
+
+    let signature_proto = proto::WindowUdfSignature::decode(bytes)
+        .map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to decode window UDF signature: {e}")))?;
+
+    let convert = || -> anyhow::Result<(Signature, DataType)> {
+        let arg_types = mappers::from_proto_arrow_types(&signature_proto.arg_types, "argument")?;
+        let return_type = mappers::from_proto_arrow_type(signature_proto.return_type.as_ref(), "return value")?;
+        let volatility = mappers::from_proto_volatility(signature_proto.volatility)?;
+
+        Ok((Signature::exact(arg_types, volatility), return_type))
+    };
+
+    convert().map_err(|e| ErrorInfo::new(ErrorCode::InvalidArgument, format!("Failed to convert window UDF signature: {e}")))
+}
@@ -0,0 +1,9 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// A configuration option of a <see cref="SessionContext"/> with its effective value.
+/// </summary>
+/// <param name="Key">Full option key, e.g. <c>datafusion.execution.batch_size</c>.</param>
+/// <param name="Value">Current value, or null if the option has no value.</param>
+/// <param name="Description">Description of the option.</param>
+public sealed record ConfigEntry(string Key, string? Value, string Description);
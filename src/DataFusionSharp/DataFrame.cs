@@ -1,8 +1,10 @@
 using System.Runtime.InteropServices;
 using Apache.Arrow;
+using DataFusionSharp.Expressions;
 using DataFusionSharp.Formats;
 using DataFusionSharp.Formats.Csv;
 using DataFusionSharp.Formats.Json;
+using DataFusionSharp.Formats.Parquet;
 using DataFusionSharp.Interop;
 
 namespace DataFusionSharp;
@@ -23,6 +25,8 @@ public sealed partial class DataFrame : IDisposable
     /// Gets the session context that created this DataFrame.
     /// </summary>
     public SessionContext Context { get; }
+
+    internal DataFrameSafeHandle Handle => _handle;
     
     internal DataFrame(SessionContext sessionContext, DataFrameSafeHandle handle)
     {
@@ -33,9 +37,11 @@ public sealed partial class DataFrame : IDisposable
     /// <summary>
     /// Returns the number of rows in this DataFrame.
     /// </summary>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
     /// <returns>A task containing the row count.</returns>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
     /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
-    public Task<ulong> CountAsync()
+    public Task<ulong> CountAsync(CancellationToken cancellationToken = default)
     {
         var (id, tcs) = AsyncOperations.Instance.Create<ulong>();
         var result = NativeMethods.DataFrameCount(_handle, CallbackForCountAsyncHandle, id);
@@ -44,6 +50,8 @@ public sealed partial class DataFrame : IDisposable
             AsyncOperations.Instance.Abort(id);
             throw new DataFusionException(result, "Failed to start counting rows in DataFrame");
         }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
         
         return tcs.Task;
     }
@@ -109,27 +117,46 @@ public sealed partial class DataFrame : IDisposable
     /// <summary>
     /// Collects all data from this DataFrame into memory.
     /// </summary>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the <see cref="DataFrameCollectedResult"/> with all record batches and schema.</returns>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Task<DataFrameCollectedResult> CollectAsync(CancellationToken cancellationToken = default) => CollectAsync(null, cancellationToken);
+
+    /// <summary>
+    /// Collects all data from this DataFrame into memory, aborting if it takes longer than <paramref name="timeout"/>.
+    /// </summary>
+    /// <param name="timeout">Maximum duration of the native execution. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
     /// <returns>A task containing the <see cref="DataFrameCollectedResult"/> with all record batches and schema.</returns>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
     /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
-    public Task<DataFrameCollectedResult> CollectAsync()
+    public Task<DataFrameCollectedResult> CollectAsync(TimeSpan? timeout, CancellationToken cancellationToken = default)
     {
+        var timeoutMillis = NativeTimeout.ToMillis(timeout);
+
         var (id, tcs) = AsyncOperations.Instance.Create<DataFrameCollectedResult>();
-        var result = NativeMethods.DataFrameCollect(_handle, CallbackForCollectHandle, id);
+        var result = NativeMethods.DataFrameCollect(_handle, timeoutMillis, CallbackForCollectHandle, id);
         if (result != DataFusionErrorCode.Ok)
         {
             AsyncOperations.Instance.Abort(id);
             throw new DataFusionException(result, "Failed to start collecting DataFrame");
         }
 
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
         return tcs.Task;
     }
     
     /// <summary>
     /// Executes the query and returns a stream of record batches.
     /// </summary>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
     /// <returns>A task containing a <see cref="DataFrameStream"/> for async enumeration.</returns>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
     /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
-    public async Task<DataFrameStream> ExecuteStreamAsync()
+    public async Task<DataFrameStream> ExecuteStreamAsync(CancellationToken cancellationToken = default)
     {
         var (id, tcs) = AsyncOperations.Instance.Create<(Schema Schema, DataFrameStreamSafeHandle StreamHandle)>();
         var result = NativeMethods.DataFrameExecuteStream(_handle, CallbackForExecutedStreamHandle, id);
@@ -139,21 +166,482 @@ public sealed partial class DataFrame : IDisposable
             throw new DataFusionException(result, "Failed to start executing stream on DataFrame");
         }
 
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
         var (schema, streamHandle) = await tcs.Task.ConfigureAwait(false);
         return new DataFrameStream(this, schema, streamHandle);
     }
 
+    /// <summary>
+    /// Computes summary statistics (count, null_count, mean, std, min, max and median) for every column.
+    /// </summary>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>
+    /// A task containing a new <see cref="DataFrame"/> with one row per statistic, named by a <c>describe</c> column,
+    /// followed by one column per column of this DataFrame.
+    /// </returns>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Task<DataFrame> DescribeAsync(CancellationToken cancellationToken = default) => DescribeAsync(null, cancellationToken);
+
+    /// <summary>
+    /// Computes summary statistics (count, null_count, mean, std, min, max and median) for every column,
+    /// aborting if it takes longer than <paramref name="timeout"/>.
+    /// </summary>
+    /// <param name="timeout">Maximum duration of the native execution. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>
+    /// A task containing a new <see cref="DataFrame"/> with one row per statistic, named by a <c>describe</c> column,
+    /// followed by one column per column of this DataFrame.
+    /// </returns>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<DataFrame> DescribeAsync(TimeSpan? timeout, CancellationToken cancellationToken = default)
+    {
+        var timeoutMillis = NativeTimeout.ToMillis(timeout);
+
+        var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+        var result = NativeMethods.DataFrameDescribe(_handle, timeoutMillis, SessionContext.CallbackForDataFrameHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start describing DataFrame");
+        }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+        return new DataFrame(Context, await tcs.Task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Executes this DataFrame once and materializes its results in memory, so that they can be consumed repeatedly
+    /// without re-executing the plan.
+    /// </summary>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing a new <see cref="DataFrame"/> backed by the materialized record batches.</returns>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Task<DataFrame> CacheAsync(CancellationToken cancellationToken = default) => CacheAsync(null, null, cancellationToken);
+
+    /// <summary>
+    /// Executes this DataFrame once and materializes its results in memory, so that they can be consumed repeatedly
+    /// without re-executing the plan.
+    /// </summary>
+    /// <param name="memoryLimit">Maximum size of the materialized record batches in bytes. If null, the size is not limited.</param>
+    /// <param name="timeout">Maximum duration of the native execution. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing a new <see cref="DataFrame"/> backed by the materialized record batches.</returns>
+    /// <exception cref="ArgumentOutOfRangeException">Thrown when <paramref name="memoryLimit"/> is zero.</exception>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <exception cref="DataFusionException">
+    /// Thrown when the operation fails, with <see cref="DataFusionErrorCode.ResourcesExhausted"/> if the results exceed <paramref name="memoryLimit"/>.
+    /// </exception>
+    public async Task<DataFrame> CacheAsync(ulong? memoryLimit, TimeSpan? timeout = null, CancellationToken cancellationToken = default)
+    {
+        if (memoryLimit.HasValue)
+            ArgumentOutOfRangeException.ThrowIfZero(memoryLimit.Value, nameof(memoryLimit));
+
+        var timeoutMillis = NativeTimeout.ToMillis(timeout);
+
+        var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+        var result = NativeMethods.DataFrameCache(_handle, memoryLimit ?? 0, timeoutMillis, SessionContext.CallbackForDataFrameHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start caching DataFrame");
+        }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+        return new DataFrame(Context, await tcs.Task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Projects this DataFrame onto a list of expressions, like the <c>SELECT</c> list of a query.
+    /// </summary>
+    /// <param name="expressions">The expressions evaluated for every row, e.g. columns, computed values or aliases.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when an expression cannot be resolved against this DataFrame.</exception>
+    public async Task<DataFrame> SelectAsync(params Expr[] expressions)
+    {
+        var proto = new Proto.LogicalExprList();
+        proto.Expr.AddRange(Expr.ToProto(expressions));
+
+        Task<DataFrameSafeHandle> task;
+        using (var exprsData = PinnedProtobufData.FromMessage(proto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.DataFrameSelect(_handle, exprsData.ToBytesData(), SessionContext.CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start selecting DataFrame columns");
+            }
+
+            task = tcs.Task;
+        }
+
+        return new DataFrame(Context, await task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Keeps only the rows for which <paramref name="predicate"/> is true, like the <c>WHERE</c> clause of a query.
+    /// </summary>
+    /// <param name="predicate">A boolean expression evaluated for every row.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the predicate cannot be resolved against this DataFrame.</exception>
+    public async Task<DataFrame> FilterAsync(Expr predicate)
+    {
+        ArgumentNullException.ThrowIfNull(predicate);
+
+        Task<DataFrameSafeHandle> task;
+        using (var predicateData = PinnedProtobufData.FromMessage(predicate.ToProto()))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.DataFrameFilter(_handle, predicateData.ToBytesData(), SessionContext.CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start filtering DataFrame");
+            }
+
+            task = tcs.Task;
+        }
+
+        return new DataFrame(Context, await task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Groups the rows by <paramref name="groupBy"/> and evaluates <paramref name="aggregates"/> for every group,
+    /// like the <c>GROUP BY</c> clause of a query.
+    /// </summary>
+    /// <param name="groupBy">The expressions to group by. If empty, all rows form a single group.</param>
+    /// <param name="aggregates">The aggregate function calls, e.g. created by <see cref="Expr.AggregateFunction(string, Expr[])"/>.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>, with the grouping columns followed by the aggregates.</returns>
+    /// <exception cref="DataFusionException">Thrown when an expression cannot be resolved against this DataFrame.</exception>
+    public async Task<DataFrame> AggregateAsync(IEnumerable<Expr> groupBy, IEnumerable<Expr> aggregates)
+    {
+        var proto = new Proto.AggregateExpressions();
+        proto.GroupExpr.AddRange(Expr.ToProto(groupBy));
+        proto.AggrExpr.AddRange(Expr.ToProto(aggregates));
+
+        Task<DataFrameSafeHandle> task;
+        using (var aggregateData = PinnedProtobufData.FromMessage(proto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.DataFrameAggregate(_handle, aggregateData.ToBytesData(), SessionContext.CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start aggregating DataFrame");
+            }
+
+            task = tcs.Task;
+        }
+
+        return new DataFrame(Context, await task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Sorts the rows, like the <c>ORDER BY</c> clause of a query.
+    /// </summary>
+    /// <param name="sortExpressions">The sort keys in order of precedence, created by <see cref="Expr.Sort"/>.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when an expression cannot be resolved against this DataFrame.</exception>
+    public async Task<DataFrame> SortAsync(params SortExpr[] sortExpressions)
+    {
+        var proto = new Proto.SortExprNodeCollection();
+        proto.SortExprNodes.AddRange(SortExpr.ToProto(sortExpressions));
+
+        Task<DataFrameSafeHandle> task;
+        using (var sortExprsData = PinnedProtobufData.FromMessage(proto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.DataFrameSort(_handle, sortExprsData.ToBytesData(), SessionContext.CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start sorting DataFrame");
+            }
+
+            task = tcs.Task;
+        }
+
+        return new DataFrame(Context, await task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Skips <paramref name="skip"/> rows and returns at most <paramref name="fetch"/> of the remaining rows,
+    /// like the <c>OFFSET</c> and <c>LIMIT</c> clauses of a query.
+    /// </summary>
+    /// <param name="fetch">Maximum number of rows to return. If null, all remaining rows are returned.</param>
+    /// <param name="skip">Number of rows to skip.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<DataFrame> LimitAsync(ulong? fetch, ulong skip = 0)
+    {
+        // Negative fetch means no limit for the native side
+        var nativeFetch = fetch.HasValue ? (long) Math.Min(fetch.Value, long.MaxValue) : -1;
+
+        var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+        var result = NativeMethods.DataFrameLimit(_handle, skip, nativeFetch, SessionContext.CallbackForDataFrameHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start limiting DataFrame");
+        }
+
+        return new DataFrame(Context, await tcs.Task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Adds a column computed by <paramref name="expression"/>, replacing an existing column with the same name.
+    /// </summary>
+    /// <param name="name">The name of the column.</param>
+    /// <param name="expression">The expression evaluated for every row.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the expression cannot be resolved against this DataFrame.</exception>
+    public async Task<DataFrame> WithColumnAsync(string name, Expr expression)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        ArgumentNullException.ThrowIfNull(expression);
+
+        Task<DataFrameSafeHandle> task;
+        using (var exprData = PinnedProtobufData.FromMessage(expression.ToProto()))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.DataFrameWithColumn(_handle, name, exprData.ToBytesData(), SessionContext.CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start adding DataFrame column");
+            }
+
+            task = tcs.Task;
+        }
+
+        return new DataFrame(Context, await task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Removes columns by name. Names that do not match a column are ignored.
+    /// </summary>
+    /// <param name="columns">The names of the columns to remove.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<DataFrame> DropColumnsAsync(params string[] columns)
+    {
+        ArgumentNullException.ThrowIfNull(columns);
+
+        var proto = new Proto.ColumnNames();
+        proto.Names.AddRange(columns);
+
+        Task<DataFrameSafeHandle> task;
+        using (var columnsData = PinnedProtobufData.FromMessage(proto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.DataFrameDropColumns(_handle, columnsData.ToBytesData(), SessionContext.CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start dropping DataFrame columns");
+            }
+
+            task = tcs.Task;
+        }
+
+        return new DataFrame(Context, await task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Joins this DataFrame with <paramref name="right"/> on equal values of paired columns and an optional filter.
+    /// </summary>
+    /// <param name="right">The DataFrame to join with. Must be created by the same <see cref="SessionContext"/>.</param>
+    /// <param name="joinType">The kind of join.</param>
+    /// <param name="leftColumns">Columns of this DataFrame compared for equality with <paramref name="rightColumns"/>, pairwise.</param>
+    /// <param name="rightColumns">Columns of <paramref name="right"/> compared for equality with <paramref name="leftColumns"/>, pairwise.</param>
+    /// <param name="filter">Optional predicate that joined rows must satisfy in addition to the equality of the columns.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">
+    /// Thrown when the DataFrames belong to different sessions, with <see cref="DataFusionErrorCode.InvalidArgument"/>,
+    /// or when a column or the filter cannot be resolved.
+    /// </exception>
+    public async Task<DataFrame> JoinAsync(DataFrame right, JoinType joinType, IEnumerable<string> leftColumns, IEnumerable<string> rightColumns, Expr? filter = null)
+    {
+        ArgumentNullException.ThrowIfNull(right);
+        ArgumentNullException.ThrowIfNull(leftColumns);
+        ArgumentNullException.ThrowIfNull(rightColumns);
+
+        var proto = new Proto.JoinOptions
+        {
+            JoinType = joinType.ToProto(),
+            Filter = filter?.ToProto()
+        };
+        proto.LeftCols.AddRange(leftColumns);
+        proto.RightCols.AddRange(rightColumns);
+
+        Task<DataFrameSafeHandle> task;
+        using (var joinOptionsData = PinnedProtobufData.FromMessage(proto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.DataFrameJoin(_handle, right._handle, joinOptionsData.ToBytesData(), SessionContext.CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start joining DataFrames");
+            }
+
+            task = tcs.Task;
+        }
+
+        return new DataFrame(Context, await task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Joins every row of this DataFrame with every row of <paramref name="right"/>.
+    /// </summary>
+    /// <param name="right">The DataFrame to join with. Must be created by the same <see cref="SessionContext"/>.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">
+    /// Thrown when the DataFrames belong to different sessions, with <see cref="DataFusionErrorCode.InvalidArgument"/>.
+    /// </exception>
+    public async Task<DataFrame> CrossJoinAsync(DataFrame right)
+    {
+        ArgumentNullException.ThrowIfNull(right);
+
+        var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+        var result = NativeMethods.DataFrameCrossJoin(_handle, right._handle, SessionContext.CallbackForDataFrameHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start cross joining DataFrames");
+        }
+
+        return new DataFrame(Context, await tcs.Task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Appends the rows of <paramref name="right"/>, keeping duplicates, like <c>UNION ALL</c>.
+    /// </summary>
+    /// <param name="right">The DataFrame to combine with. Must be created by the same <see cref="SessionContext"/> and have compatible columns.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the DataFrames belong to different sessions or their columns are not compatible.</exception>
+    public Task<DataFrame> UnionAsync(DataFrame right) => CombineAsync(right, NativeMethods.DataFrameUnion, "UNION ALL");
+
+    /// <summary>
+    /// Appends the rows of <paramref name="right"/> and removes duplicates, like <c>UNION</c>.
+    /// </summary>
+    /// <param name="right">The DataFrame to combine with. Must be created by the same <see cref="SessionContext"/> and have compatible columns.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the DataFrames belong to different sessions or their columns are not compatible.</exception>
+    public Task<DataFrame> UnionDistinctAsync(DataFrame right) => CombineAsync(right, NativeMethods.DataFrameUnionDistinct, "UNION DISTINCT");
+
+    /// <summary>
+    /// Keeps the rows that also appear in <paramref name="right"/>, including duplicates, like <c>INTERSECT ALL</c>.
+    /// Use <see cref="IntersectDistinctAsync"/> for the SQL default <c>INTERSECT</c>, which removes duplicates.
+    /// </summary>
+    /// <param name="right">The DataFrame to combine with. Must be created by the same <see cref="SessionContext"/> and have compatible columns.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the DataFrames belong to different sessions or their columns are not compatible.</exception>
+    public Task<DataFrame> IntersectAsync(DataFrame right) => CombineAsync(right, NativeMethods.DataFrameIntersect, "INTERSECT ALL");
+
+    /// <summary>
+    /// Keeps the distinct rows that also appear in <paramref name="right"/>, like <c>INTERSECT</c>.
+    /// </summary>
+    /// <param name="right">The DataFrame to combine with. Must be created by the same <see cref="SessionContext"/> and have compatible columns.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the DataFrames belong to different sessions or their columns are not compatible.</exception>
+    public Task<DataFrame> IntersectDistinctAsync(DataFrame right) => CombineAsync(right, NativeMethods.DataFrameIntersectDistinct, "INTERSECT DISTINCT");
+
+    /// <summary>
+    /// Keeps the rows that do not appear in <paramref name="right"/>, including duplicates, like <c>EXCEPT ALL</c>.
+    /// Use <see cref="ExceptDistinctAsync"/> for the SQL default <c>EXCEPT</c>, which removes duplicates.
+    /// </summary>
+    /// <param name="right">The DataFrame to combine with. Must be created by the same <see cref="SessionContext"/> and have compatible columns.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the DataFrames belong to different sessions or their columns are not compatible.</exception>
+    public Task<DataFrame> ExceptAsync(DataFrame right) => CombineAsync(right, NativeMethods.DataFrameExcept, "EXCEPT ALL");
+
+    /// <summary>
+    /// Keeps the distinct rows that do not appear in <paramref name="right"/>, like <c>EXCEPT</c>.
+    /// </summary>
+    /// <param name="right">The DataFrame to combine with. Must be created by the same <see cref="SessionContext"/> and have compatible columns.</param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the DataFrames belong to different sessions or their columns are not compatible.</exception>
+    public Task<DataFrame> ExceptDistinctAsync(DataFrame right) => CombineAsync(right, NativeMethods.DataFrameExceptDistinct, "EXCEPT DISTINCT");
+
+    /// <summary>
+    /// Removes duplicate rows, like <c>SELECT DISTINCT</c>.
+    /// </summary>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<DataFrame> DistinctAsync()
+    {
+        var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+        var result = NativeMethods.DataFrameDistinct(_handle, SessionContext.CallbackForDataFrameHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start removing duplicate DataFrame rows");
+        }
+
+        return new DataFrame(Context, await tcs.Task.ConfigureAwait(false));
+    }
+
+    /// <summary>
+    /// Keeps one row for every distinct value of <paramref name="onExpressions"/>, like <c>SELECT DISTINCT ON (...)</c>.
+    /// </summary>
+    /// <param name="onExpressions">The expressions whose combined values identify distinct rows.</param>
+    /// <param name="selectExpressions">The expressions to return for every distinct row.</param>
+    /// <param name="sortExpressions">
+    /// Optional ordering that decides which row is kept for each distinct value; it must start with <paramref name="onExpressions"/>.
+    /// If null or empty, an arbitrary row is kept.
+    /// </param>
+    /// <returns>A task containing the new <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when an expression cannot be resolved against this DataFrame.</exception>
+    public async Task<DataFrame> DistinctOnAsync(IEnumerable<Expr> onExpressions, IEnumerable<Expr> selectExpressions, IEnumerable<SortExpr>? sortExpressions = null)
+    {
+        var proto = new Proto.DistinctOnExpressions();
+        proto.OnExpr.AddRange(Expr.ToProto(onExpressions));
+        proto.SelectExpr.AddRange(Expr.ToProto(selectExpressions));
+        if (sortExpressions is not null)
+            proto.SortExpr.AddRange(SortExpr.ToProto(sortExpressions));
+
+        Task<DataFrameSafeHandle> task;
+        using (var distinctOnData = PinnedProtobufData.FromMessage(proto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.DataFrameDistinctOn(_handle, distinctOnData.ToBytesData(), SessionContext.CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start removing duplicate DataFrame rows");
+            }
+
+            task = tcs.Task;
+        }
+
+        return new DataFrame(Context, await task.ConfigureAwait(false));
+    }
+
     /// <summary>
     /// Writes the DataFrame contents to a CSV file.
     /// </summary>
     /// <param name="path">The output file path.</param>
     /// <param name="dataFrameWriteOptions">Optional DataFrame writing options.</param>
     /// <param name="csvWriteOptions">Optional CSV writing options.</param>
+    /// <param name="timeout">Maximum duration of the native execution. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
     /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
     /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
-    public Task WriteCsvAsync(string path, DataFrameWriteOptions? dataFrameWriteOptions = null, CsvWriteOptions? csvWriteOptions = null)
+    public Task WriteCsvAsync(string path, DataFrameWriteOptions? dataFrameWriteOptions = null, CsvWriteOptions? csvWriteOptions = null, TimeSpan? timeout = null, CancellationToken cancellationToken = default)
     {
         ArgumentException.ThrowIfNullOrEmpty(path);
+        var timeoutMillis = NativeTimeout.ToMillis(timeout);
 
         using var dataFrameOptionsData = PinnedProtobufData.FromMessage(dataFrameWriteOptions?.ToProto());
         using var csvOptionsData = PinnedProtobufData.FromMessage(csvWriteOptions?.ToProto());
@@ -161,13 +649,15 @@ public sealed partial class DataFrame : IDisposable
         var (id, tcs) = AsyncOperations.Instance.Create();
         var result = NativeMethods.DataFrameWriteCsv(_handle, path,
             dataFrameOptionsData.ToBytesData(), csvOptionsData.ToBytesData(),
-            GenericCallbacks.CallbackForVoidHandle, id);
+            timeoutMillis, GenericCallbacks.CallbackForVoidHandle, id);
         if (result != DataFusionErrorCode.Ok)
         {
             AsyncOperations.Instance.Abort(id);
             throw new DataFusionException(result, "Failed to start writing DataFrame to CSV");
         }
 
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
         return tcs.Task;
     }
 
@@ -177,11 +667,16 @@ public sealed partial class DataFrame : IDisposable
     /// <param name="path">The output file path.</param>
     /// <param name="dataFrameWriteOptions">Optional DataFrame writing options.</param>
     /// <param name="jsonWriteOptions">Optional JSON writing jsonWriteOptions.</param>
+    /// <param name="timeout">Maximum duration of the native execution. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
     /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
     /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
-    public Task WriteJsonAsync(string path, DataFrameWriteOptions? dataFrameWriteOptions = null, JsonWriteOptions? jsonWriteOptions = null)
+    public Task WriteJsonAsync(string path, DataFrameWriteOptions? dataFrameWriteOptions = null, JsonWriteOptions? jsonWriteOptions = null, TimeSpan? timeout = null, CancellationToken cancellationToken = default)
     {
         ArgumentException.ThrowIfNullOrEmpty(path);
+        var timeoutMillis = NativeTimeout.ToMillis(timeout);
 
         using var dataFrameOptionsData = PinnedProtobufData.FromMessage(dataFrameWriteOptions?.ToProto());
         using var optionsData = PinnedProtobufData.FromMessage(jsonWriteOptions?.ToProto());
@@ -189,13 +684,15 @@ public sealed partial class DataFrame : IDisposable
         var (id, tcs) = AsyncOperations.Instance.Create();
         var result = NativeMethods.DataFrameWriteJson(_handle, path,
             dataFrameOptionsData.ToBytesData(), optionsData.ToBytesData(), 
-            GenericCallbacks.CallbackForVoidHandle, id);
+            timeoutMillis, GenericCallbacks.CallbackForVoidHandle, id);
         if (result != DataFusionErrorCode.Ok)
         {
             AsyncOperations.Instance.Abort(id);
             throw new DataFusionException(result, "Failed to start writing DataFrame to JSON");
         }
 
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
         return tcs.Task;
     }
 
@@ -203,20 +700,34 @@ public sealed partial class DataFrame : IDisposable
     /// Writes the DataFrame contents to a Parquet file.
     /// </summary>
     /// <param name="path">The output file path.</param>
+    /// <param name="dataFrameWriteOptions">Optional DataFrame writing options.</param>
+    /// <param name="parquetWriteOptions">Optional Parquet writing options.</param>
+    /// <param name="timeout">Maximum duration of the native execution. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
     /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
     /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
-    public Task WriteParquetAsync(string path)
+    public Task WriteParquetAsync(string path, DataFrameWriteOptions? dataFrameWriteOptions = null, ParquetWriteOptions? parquetWriteOptions = null, TimeSpan? timeout = null, CancellationToken cancellationToken = default)
     {
         ArgumentException.ThrowIfNullOrEmpty(path);
-        
+        var timeoutMillis = NativeTimeout.ToMillis(timeout);
+
+        using var dataFrameOptionsData = PinnedProtobufData.FromMessage(dataFrameWriteOptions?.ToProto());
+        using var optionsData = PinnedProtobufData.FromMessage(parquetWriteOptions?.ToProto());
+
         var (id, tcs) = AsyncOperations.Instance.Create();
-        var result = NativeMethods.DataFrameWriteParquet(_handle, path, GenericCallbacks.CallbackForVoidHandle, id);
+        var result = NativeMethods.DataFrameWriteParquet(_handle, path,
+            dataFrameOptionsData.ToBytesData(), optionsData.ToBytesData(),
+            timeoutMillis, GenericCallbacks.CallbackForVoidHandle, id);
         if (result != DataFusionErrorCode.Ok)
         {
             AsyncOperations.Instance.Abort(id);
             throw new DataFusionException(result, "Failed to start writing DataFrame to Parquet");
         }
 
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
         return tcs.Task;
     }
     
@@ -225,6 +736,21 @@ public sealed partial class DataFrame : IDisposable
     {
         _handle.Dispose();
     }
+
+    private async Task<DataFrame> CombineAsync(DataFrame right, Func<DataFrameSafeHandle, DataFrameSafeHandle, IntPtr, ulong, DataFusionErrorCode> combine, string operation)
+    {
+        ArgumentNullException.ThrowIfNull(right);
+
+        var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+        var result = combine(_handle, right._handle, SessionContext.CallbackForDataFrameHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, $"Failed to start {operation} of DataFrames");
+        }
+
+        return new DataFrame(Context, await tcs.Task.ConfigureAwait(false));
+    }
     
     [DataFusionSharpNativeCallback]
     private static void CallbackForCountAsync(IntPtr result, IntPtr error, ulong handle)
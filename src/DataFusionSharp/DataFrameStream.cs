@@ -36,6 +36,15 @@ public sealed partial class DataFrameStream : IAsyncEnumerable<RecordBatch>, IDi
     /// Gets the <see cref="Apache.Arrow.Schema" /> of the record batches produced by this stream.
     /// </summary>
     public Schema Schema { get; }
+
+    /// <summary>
+    /// Gets or sets the maximum time to wait for each record batch.
+    /// If null or <see cref="Timeout.InfiniteTimeSpan"/>, waiting for a batch does not time out.
+    /// </summary>
+    /// <remarks>
+    /// When a batch is not produced in time, the enumeration fails with a <see cref="TimeoutException"/>.
+    /// </remarks>
+    public TimeSpan? BatchTimeout { get; set; }
     
     internal DataFrameStream(DataFrame dataFrame, Schema schema, DataFrameStreamSafeHandle handle)
     {
@@ -47,11 +56,12 @@ public sealed partial class DataFrameStream : IAsyncEnumerable<RecordBatch>, IDi
     /// <summary>
     /// Returns an async enumerator that iterates through the record batches.
     /// </summary>
-    /// <param name="cancellationToken">A token to cancel the enumeration.</param>
+    /// <param name="cancellationToken">A token to cancel the enumeration; it also aborts the native retrieval of a pending batch.</param>
     /// <returns>An async enumerator of <see cref="RecordBatch"/>.</returns>
+    /// <exception cref="OperationCanceledException">Thrown when the enumeration is cancelled.</exception>
     public async IAsyncEnumerator<RecordBatch> GetAsyncEnumerator(CancellationToken cancellationToken = default)
     {
-        while (await NextAsync().ConfigureAwait(false) is { } batch)
+        while (await NextAsync(cancellationToken).ConfigureAwait(false) is { } batch)
         {
             cancellationToken.ThrowIfCancellationRequested();
             yield return batch;
@@ -69,16 +79,19 @@ public sealed partial class DataFrameStream : IAsyncEnumerable<RecordBatch>, IDi
         _handle.Dispose();
     }
     
-    private async Task<RecordBatch?> NextAsync()
+    private async Task<RecordBatch?> NextAsync(CancellationToken cancellationToken)
     {
+        var timeoutMillis = NativeTimeout.ToMillis(BatchTimeout);
         var (id, tcs) = AsyncOperations.Instance.Create<RecordBatch?, Schema>(Schema);
         
-        var result = NativeMethods.DataFrameStreamNext(_handle, CallbackForNextResultHandle, id);
+        var result = NativeMethods.DataFrameStreamNext(_handle, timeoutMillis, CallbackForNextResultHandle, id);
         if (result != DataFusionErrorCode.Ok)
         {
             AsyncOperations.Instance.Abort(id);
             throw new DataFusionException(result, "Failed to start getting next batch from stream");
         }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
         
         var batch = await tcs.Task.ConfigureAwait(false);
 
@@ -20,5 +20,15 @@ public enum DataFusionErrorCode
     /// <summary>An error occurred while executing SQL.</summary>
     SqlError = 6,
     /// <summary>An error occurred during DataFrame operations.</summary>
-    DataFrameError = 7
+    DataFrameError = 7,
+    /// <summary>An error occurred while managing catalogs or schemas.</summary>
+    CatalogError = 8,
+    /// <summary>A query exceeded a resource limit, such as the memory limit or the spill directory size.</summary>
+    ResourcesExhausted = 9,
+    /// <summary>The operation was cancelled before it completed.</summary>
+    Cancelled = 10,
+    /// <summary>The operation did not complete before its deadline.</summary>
+    Timeout = 11,
+    /// <summary>A SQL statement was rejected because its kind is not allowed by the SQL options.</summary>
+    StatementNotAllowed = 12
 }
@@ -44,11 +44,15 @@ public sealed class DataFusionRuntime : IDisposable
     /// <summary>
     /// Creates a new session context for executing queries.
     /// </summary>
+    /// <param name="options">Session configuration. If null, DataFusion defaults are used.</param>
     /// <returns>A new <see cref="SessionContext"/> instance.</returns>
-    /// <exception cref="DataFusionException">Thrown when context creation fails.</exception>
-    public SessionContext CreateSessionContext()
+    /// <exception cref="DataFusionException">Thrown when context creation fails, including when an option is unknown or invalid.</exception>
+    public SessionContext CreateSessionContext(SessionContextOptions? options = null)
     {
-        var errorCode = NativeMethods.ContextNew(_handle, out var contextHandle);
+        using var configData = PinnedProtobufData.FromMessage(options?.ToProto());
+        using var runtimeEnvData = PinnedProtobufData.FromMessage(options?.ToRuntimeEnvProto());
+
+        var errorCode = NativeMethods.ContextNew(_handle, configData.ToBytesData(), runtimeEnvData.ToBytesData(), out var contextHandle);
         DataFusionException.ThrowIfError(errorCode, "Failed to create DataFusion context");
         
         return new SessionContext(this, new SessionContextSafeHandle(contextHandle));
@@ -0,0 +1,257 @@
+using System.Runtime.CompilerServices;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Expressions;
+
+/// <summary>
+/// Represents a logical expression, such as a column reference, a literal or a function call,
+/// used to build DataFrame transformations.
+/// </summary>
+/// <remarks>
+/// Expressions are immutable; every method returns a new expression. Functions are resolved by name
+/// in the session of the DataFrame the expression is applied to, so user-defined functions can be called as well.
+/// </remarks>
+/// <example>
+/// <code language="csharp">
+/// var predicate = Expr.Column("price").Gt(Expr.Literal(100)).And(Expr.Column("region").IsNotNull());
+/// using var filtered = await dataFrame.FilterAsync(predicate);
+/// </code>
+/// </example>
+public sealed class Expr
+{
+    private readonly Proto.LogicalExprNode _proto;
+
+    private Expr(Proto.LogicalExprNode proto)
+    {
+        _proto = proto;
+    }
+
+    /// <summary>
+    /// Creates a reference to a column.
+    /// </summary>
+    /// <param name="name">The column name.</param>
+    /// <param name="relation">Optional table name qualifying the column, needed when a name is ambiguous, e.g. after a join.</param>
+    /// <returns>The column expression.</returns>
+    public static Expr Column(string name, string? relation = null)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+
+        var column = new Proto.Column { Name = name };
+        if (relation is not null)
+            column.Relation = new Proto.ColumnRelation { Relation = relation };
+
+        return new Expr(new Proto.LogicalExprNode { Column = column });
+    }
+
+    /// <summary>
+    /// Creates a literal value.
+    /// </summary>
+    /// <param name="value">The value: a primitive type, string, byte array or null.</param>
+    /// <returns>The literal expression.</returns>
+    /// <exception cref="ArgumentException">Thrown when the type of <paramref name="value"/> is not supported.</exception>
+    public static Expr Literal(object? value) => new(new Proto.LogicalExprNode { Literal = value.ToProtoScalarValue() });
+
+    /// <summary>
+    /// Creates a call of a scalar function, either built-in (e.g. <c>abs</c>, <c>upper</c>) or registered in the session.
+    /// </summary>
+    /// <param name="name">The function name.</param>
+    /// <param name="arguments">The function arguments.</param>
+    /// <returns>The function call expression.</returns>
+    public static Expr Function(string name, params Expr[] arguments)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+
+        var node = new Proto.ScalarUDFExprNode { FunName = name };
+        node.Args.AddRange(ToProto(arguments));
+
+        return new Expr(new Proto.LogicalExprNode { ScalarUdfExpr = node });
+    }
+
+    /// <summary>
+    /// Creates a call of an aggregate function, either built-in (e.g. <c>sum</c>, <c>count</c>) or registered in the session.
+    /// </summary>
+    /// <param name="name">The function name.</param>
+    /// <param name="arguments">The function arguments.</param>
+    /// <returns>The aggregate function call expression.</returns>
+    public static Expr AggregateFunction(string name, params Expr[] arguments) => AggregateFunction(name, distinct: false, arguments);
+
+    /// <summary>
+    /// Creates a call of an aggregate function, either built-in (e.g. <c>sum</c>, <c>count</c>) or registered in the session.
+    /// </summary>
+    /// <param name="name">The function name.</param>
+    /// <param name="distinct">Whether the function only aggregates distinct argument values, as in <c>count(DISTINCT x)</c>.</param>
+    /// <param name="arguments">The function arguments.</param>
+    /// <returns>The aggregate function call expression.</returns>
+    public static Expr AggregateFunction(string name, bool distinct, params Expr[] arguments)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+
+        var node = new Proto.AggregateUDFExprNode { FunName = name, Distinct = distinct };
+        node.Args.AddRange(ToProto(arguments));
+
+        return new Expr(new Proto.LogicalExprNode { AggregateUdfExpr = node });
+    }
+
+    /// <summary>
+    /// Names the result of this expression.
+    /// </summary>
+    /// <param name="name">The name of the resulting column.</param>
+    /// <returns>The aliased expression.</returns>
+    public Expr Alias(string name)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+
+        return new Expr(new Proto.LogicalExprNode { Alias = new Proto.AliasNode { Expr = _proto, Alias = name } });
+    }
+
+    /// <summary>Creates an <c>=</c> comparison with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The comparison expression.</returns>
+    public Expr Eq(Expr other) => Binary(other, "Eq");
+
+    /// <summary>Creates a <c>&lt;&gt;</c> comparison with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The comparison expression.</returns>
+    public Expr NotEq(Expr other) => Binary(other, "NotEq");
+
+    /// <summary>Creates a <c>&lt;</c> comparison with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The comparison expression.</returns>
+    public Expr Lt(Expr other) => Binary(other, "Lt");
+
+    /// <summary>Creates a <c>&lt;=</c> comparison with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The comparison expression.</returns>
+    public Expr LtEq(Expr other) => Binary(other, "LtEq");
+
+    /// <summary>Creates a <c>&gt;</c> comparison with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The comparison expression.</returns>
+    public Expr Gt(Expr other) => Binary(other, "Gt");
+
+    /// <summary>Creates a <c>&gt;=</c> comparison with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The comparison expression.</returns>
+    public Expr GtEq(Expr other) => Binary(other, "GtEq");
+
+    /// <summary>Creates a <c>+</c> operation with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The arithmetic expression.</returns>
+    public Expr Add(Expr other) => Binary(other, "Plus");
+
+    /// <summary>Creates a <c>-</c> operation with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The arithmetic expression.</returns>
+    public Expr Subtract(Expr other) => Binary(other, "Minus");
+
+    /// <summary>Creates a <c>*</c> operation with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The arithmetic expression.</returns>
+    public Expr Multiply(Expr other) => Binary(other, "Multiply");
+
+    /// <summary>Creates a <c>/</c> operation with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The arithmetic expression.</returns>
+    public Expr Divide(Expr other) => Binary(other, "Divide");
+
+    /// <summary>Creates a <c>%</c> operation with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The arithmetic expression.</returns>
+    public Expr Modulo(Expr other) => Binary(other, "Modulo");
+
+    /// <summary>Creates a logical <c>AND</c> with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The logical expression.</returns>
+    public Expr And(Expr other) => Binary(other, "And");
+
+    /// <summary>Creates a logical <c>OR</c> with <paramref name="other"/>.</summary>
+    /// <param name="other">The right operand.</param>
+    /// <returns>The logical expression.</returns>
+    public Expr Or(Expr other) => Binary(other, "Or");
+
+    /// <summary>Creates a logical <c>NOT</c> of this expression.</summary>
+    /// <returns>The negated expression.</returns>
+    public Expr Not() => new(new Proto.LogicalExprNode { NotExpr = new Proto.Not { Expr = _proto } });
+
+    /// <summary>Creates an arithmetic negation of this expression.</summary>
+    /// <returns>The negated expression.</returns>
+    public Expr Negate() => new(new Proto.LogicalExprNode { Negative = new Proto.NegativeNode { Expr = _proto } });
+
+    /// <summary>Creates an <c>IS NULL</c> check of this expression.</summary>
+    /// <returns>The boolean expression.</returns>
+    public Expr IsNull() => new(new Proto.LogicalExprNode { IsNullExpr = new Proto.IsNull { Expr = _proto } });
+
+    /// <summary>Creates an <c>IS NOT NULL</c> check of this expression.</summary>
+    /// <returns>The boolean expression.</returns>
+    public Expr IsNotNull() => new(new Proto.LogicalExprNode { IsNotNullExpr = new Proto.IsNotNull { Expr = _proto } });
+
+    /// <summary>
+    /// Creates a <c>BETWEEN</c> check of this expression, inclusive of both bounds.
+    /// </summary>
+    /// <param name="low">The lower bound.</param>
+    /// <param name="high">The upper bound.</param>
+    /// <returns>The boolean expression.</returns>
+    public Expr Between(Expr low, Expr high)
+    {
+        ArgumentNullException.ThrowIfNull(low);
+        ArgumentNullException.ThrowIfNull(high);
+
+        return new Expr(new Proto.LogicalExprNode { Between = new Proto.BetweenNode { Expr = _proto, Low = low._proto, High = high._proto } });
+    }
+
+    /// <summary>
+    /// Creates an <c>IN</c> check of this expression against a list of values.
+    /// </summary>
+    /// <param name="values">The values to compare with.</param>
+    /// <returns>The boolean expression.</returns>
+    public Expr In(params Expr[] values)
+    {
+        var node = new Proto.InListNode { Expr = _proto };
+        node.List.AddRange(ToProto(values));
+
+        return new Expr(new Proto.LogicalExprNode { InList = node });
+    }
+
+    /// <summary>
+    /// Converts this expression to another type.
+    /// </summary>
+    /// <param name="type">The target Arrow type.</param>
+    /// <returns>The cast expression.</returns>
+    public Expr Cast(IArrowType type)
+    {
+        ArgumentNullException.ThrowIfNull(type);
+
+        return new Expr(new Proto.LogicalExprNode { Cast = new Proto.CastNode { Expr = _proto, ArrowType = type.ToProto() } });
+    }
+
+    /// <summary>
+    /// Creates a sort key ordering rows by this expression.
+    /// </summary>
+    /// <param name="ascending">Whether to sort in ascending order.</param>
+    /// <param name="nullsFirst">Whether null values are placed before non-null values.</param>
+    /// <returns>The sort expression.</returns>
+    public SortExpr Sort(bool ascending = true, bool nullsFirst = false) => new(this, ascending, nullsFirst);
+
+    /// <inheritdoc />
+    public override string ToString() => _proto.ToString();
+
+    internal Proto.LogicalExprNode ToProto() => _proto;
+
+    internal static IEnumerable<Proto.LogicalExprNode> ToProto(IEnumerable<Expr> expressions, [CallerArgumentExpression(nameof(expressions))] string? paramName = null)
+    {
+        ArgumentNullException.ThrowIfNull(expressions, paramName);
+
+        return expressions.Select(e => e?._proto ?? throw new ArgumentException("Expressions must not contain null", paramName)).ToList();
+    }
+
+    private Expr Binary(Expr other, string op)
+    {
+        ArgumentNullException.ThrowIfNull(other);
+
+        var node = new Proto.BinaryExprNode { Op = op };
+        node.Operands.Add(_proto);
+        node.Operands.Add(other._proto);
+
+        return new Expr(new Proto.LogicalExprNode { BinaryExpr = node });
+    }
+}
@@ -0,0 +1,48 @@
+using System.Runtime.CompilerServices;
+
+namespace DataFusionSharp.Expressions;
+
+/// <summary>
+/// Represents a sort key: an expression together with the direction and the placement of nulls.
+/// </summary>
+/// <remarks>
+/// Created by <see cref="Expr.Sort"/>.
+/// </remarks>
+public sealed class SortExpr
+{
+    /// <summary>
+    /// Gets the expression whose values order the rows.
+    /// </summary>
+    public Expr Expression { get; }
+
+    /// <summary>
+    /// Gets whether rows are sorted in ascending order.
+    /// </summary>
+    public bool Ascending { get; }
+
+    /// <summary>
+    /// Gets whether null values are placed before non-null values.
+    /// </summary>
+    public bool NullsFirst { get; }
+
+    internal SortExpr(Expr expression, bool ascending, bool nullsFirst)
+    {
+        Expression = expression;
+        Ascending = ascending;
+        NullsFirst = nullsFirst;
+    }
+
+    internal Proto.SortExprNode ToProto() => new()
+    {
+        Expr = Expression.ToProto(),
+        Asc = Ascending,
+        NullsFirst = NullsFirst
+    };
+
+    internal static IEnumerable<Proto.SortExprNode> ToProto(IEnumerable<SortExpr> expressions, [CallerArgumentExpression(nameof(expressions))] string? paramName = null)
+    {
+        ArgumentNullException.ThrowIfNull(expressions, paramName);
+
+        return expressions.Select(e => e?.ToProto() ?? throw new ArgumentException("Sort expressions must not contain null", paramName)).ToList();
+    }
+}
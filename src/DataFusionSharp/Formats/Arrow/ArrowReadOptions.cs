@@ -0,0 +1,26 @@
+using Apache.Arrow;
+
+namespace DataFusionSharp.Formats.Arrow;
+
+/// <summary>
+/// Options for reading Arrow IPC files.
+/// </summary>
+public sealed class ArrowReadOptions
+{
+    /// <summary>
+    /// File extension filter. If null, DataFusion uses its default (".arrow").
+    /// </summary>
+    public string? FileExtension { get; set; }
+
+    /// <summary>
+    /// Partition columns for hive-style partitioned reads.
+    /// Each entry specifies a column name and its Arrow data type.
+    /// Empty if non-partitioned read.
+    /// </summary>
+    public IReadOnlyList<PartitionColumn>? TablePartitionCols { get; set; }
+
+    /// <summary>
+    /// Explicit schema for the Arrow data. If null, the schema is read from the files.
+    /// </summary>
+    public Schema? Schema { get; set; }
+}
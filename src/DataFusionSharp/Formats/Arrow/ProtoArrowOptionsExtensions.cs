@@ -0,0 +1,20 @@
+namespace DataFusionSharp.Formats.Arrow;
+
+internal static class ProtoArrowOptionsExtensions
+{
+    internal static Proto.ArrowReadOptions ToProto(this ArrowReadOptions options)
+    {
+        var proto = new Proto.ArrowReadOptions();
+
+        if (!string.IsNullOrEmpty(options.FileExtension))
+            proto.FileExtension = options.FileExtension.ToProto();
+
+        if (options.TablePartitionCols is { Count: > 0 })
+            proto.TablePartitionCols.AddRange(options.TablePartitionCols.ToProto());
+
+        if (options.Schema is not null)
+            proto.Schema = options.Schema.ToProto();
+
+        return proto;
+    }
+}
@@ -0,0 +1,41 @@
+using Apache.Arrow;
+
+namespace DataFusionSharp.Formats.Parquet;
+
+/// <summary>
+/// Options for reading Parquet files.
+/// </summary>
+public sealed class ParquetReadOptions
+{
+    /// <summary>
+    /// File extension filter. If null, DataFusion uses its default (".parquet").
+    /// </summary>
+    public string? FileExtension { get; set; }
+
+    /// <summary>
+    /// Partition columns for hive-style partitioned reads.
+    /// Each entry specifies a column name and its Arrow data type.
+    /// Empty if non-partitioned read.
+    /// </summary>
+    public IReadOnlyList<PartitionColumn>? TablePartitionCols { get; set; }
+
+    /// <summary>
+    /// Whether the reader should use the predicate to prune row groups. If null, the session configuration value is used.
+    /// </summary>
+    public bool? ParquetPruning { get; set; }
+
+    /// <summary>
+    /// Whether the reader should skip the metadata stored in the file schema. If null, the session configuration value is used.
+    /// </summary>
+    public bool? SkipMetadata { get; set; }
+
+    /// <summary>
+    /// Explicit schema for the Parquet data. If null, DataFusion infers the schema from the files.
+    /// </summary>
+    public Schema? Schema { get; set; }
+
+    /// <summary>
+    /// Metadata size hint in bytes used when reading Parquet file footers. If null, DataFusion uses its default.
+    /// </summary>
+    public ulong? MetadataSizeHint { get; set; }
+}
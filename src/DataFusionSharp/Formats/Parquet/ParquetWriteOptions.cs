@@ -0,0 +1,129 @@
+namespace DataFusionSharp.Formats.Parquet;
+
+/// <summary>
+/// Options for writing Parquet files. Options left null keep the session's <c>datafusion.execution.parquet.*</c> settings.
+/// </summary>
+public sealed class ParquetWriteOptions
+{
+    /// <summary>
+    /// Compression codec with an optional level, for example "snappy", "zstd(3)" or "gzip(6)".
+    /// If null, the session setting is used (DataFusion default: "zstd(3)").
+    /// </summary>
+    public string? Compression { get; set; }
+
+    /// <summary>
+    /// Whether dictionary encoding is enabled. If null, the session setting is used (DataFusion default: true).
+    /// </summary>
+    public bool? DictionaryEnabled { get; set; }
+
+    /// <summary>
+    /// Statistics level to write: "none", "chunk" or "page". If null, the session setting is used (DataFusion default: "page").
+    /// </summary>
+    public string? StatisticsEnabled { get; set; }
+
+    /// <summary>
+    /// Maximum number of rows in a row group. If null, the session setting is used (DataFusion default: 1048576).
+    /// </summary>
+    public ulong? MaxRowGroupSize { get; set; }
+
+    /// <summary>
+    /// Best-effort maximum size of a data page in bytes. If null, the session setting is used (DataFusion default: 1048576).
+    /// </summary>
+    public ulong? DataPageSizeLimit { get; set; }
+
+    /// <summary>
+    /// Best-effort maximum number of rows in a data page. If null, the session setting is used (DataFusion default: 20000).
+    /// </summary>
+    public ulong? DataPageRowCountLimit { get; set; }
+
+    /// <summary>
+    /// Best-effort maximum size of a dictionary page in bytes. If null, the session setting is used (DataFusion default: 1048576).
+    /// </summary>
+    public ulong? DictionaryPageSizeLimit { get; set; }
+
+    /// <summary>
+    /// Number of rows written to a column writer at once. If null, the session setting is used (DataFusion default: 1024).
+    /// </summary>
+    public ulong? WriteBatchSize { get; set; }
+
+    /// <summary>
+    /// Parquet writer version: "1.0" or "2.0". If null, the session setting is used (DataFusion default: "1.0").
+    /// </summary>
+    public string? WriterVersion { get; set; }
+
+    /// <summary>
+    /// Default encoding for columns, for example "plain" or "delta_binary_packed". If null, the writer chooses per column.
+    /// </summary>
+    public string? Encoding { get; set; }
+
+    /// <summary>
+    /// Whether bloom filters are written for all columns. If null, the session setting is used (DataFusion default: false).
+    /// </summary>
+    public bool? BloomFilterOnWrite { get; set; }
+
+    /// <summary>
+    /// Bloom filter false positive probability. If null, the writer default is used.
+    /// </summary>
+    public double? BloomFilterFpp { get; set; }
+
+    /// <summary>
+    /// Bloom filter number of distinct values. If null, the writer default is used.
+    /// </summary>
+    public ulong? BloomFilterNdv { get; set; }
+
+    /// <summary>
+    /// Value written to the "created_by" field of the file metadata. If null, the session setting is used.
+    /// </summary>
+    public string? CreatedBy { get; set; }
+
+    /// <summary>
+    /// Additional key-value pairs written to the file metadata.
+    /// </summary>
+    public IReadOnlyDictionary<string, string>? KeyValueMetadata { get; set; }
+
+    /// <summary>
+    /// Per-column overrides keyed by column name.
+    /// </summary>
+    public IReadOnlyDictionary<string, ParquetColumnOptions>? ColumnOptions { get; set; }
+}
+
+/// <summary>
+/// Parquet writer options that override the global settings for a single column.
+/// </summary>
+public sealed class ParquetColumnOptions
+{
+    /// <summary>
+    /// Compression codec with an optional level, for example "snappy" or "zstd(3)". If null, the global setting is used.
+    /// </summary>
+    public string? Compression { get; set; }
+
+    /// <summary>
+    /// Whether dictionary encoding is enabled. If null, the global setting is used.
+    /// </summary>
+    public bool? DictionaryEnabled { get; set; }
+
+    /// <summary>
+    /// Statistics level to write: "none", "chunk" or "page". If null, the global setting is used.
+    /// </summary>
+    public string? StatisticsEnabled { get; set; }
+
+    /// <summary>
+    /// Encoding for the column. If null, the global setting is used.
+    /// </summary>
+    public string? Encoding { get; set; }
+
+    /// <summary>
+    /// Whether a bloom filter is written for the column. If null, the global setting is used.
+    /// </summary>
+    public bool? BloomFilterEnabled { get; set; }
+
+    /// <summary>
+    /// Bloom filter false positive probability. If null, the global setting is used.
+    /// </summary>
+    public double? BloomFilterFpp { get; set; }
+
+    /// <summary>
+    /// Bloom filter number of distinct values. If null, the global setting is used.
+    /// </summary>
+    public ulong? BloomFilterNdv { get; set; }
+}
@@ -0,0 +1,122 @@
+namespace DataFusionSharp.Formats.Parquet;
+
+internal static class ProtoParquetOptionsExtensions
+{
+    internal static Proto.ParquetReadOptions ToProto(this ParquetReadOptions options)
+    {
+        var proto = new Proto.ParquetReadOptions();
+
+        if (!string.IsNullOrEmpty(options.FileExtension))
+            proto.FileExtension = options.FileExtension.ToProto();
+
+        if (options.TablePartitionCols is { Count: > 0 })
+            proto.TablePartitionCols.AddRange(options.TablePartitionCols.ToProto());
+
+        if (options.ParquetPruning.HasValue)
+            proto.ParquetPruning = options.ParquetPruning.Value;
+
+        if (options.SkipMetadata.HasValue)
+            proto.SkipMetadata = options.SkipMetadata.Value;
+
+        if (options.Schema is not null)
+            proto.Schema = options.Schema.ToProto();
+
+        if (options.MetadataSizeHint.HasValue)
+            proto.MetadataSizeHint = options.MetadataSizeHint.Value;
+
+        return proto;
+    }
+
+    internal static Proto.ParquetWriteOptions ToProto(this ParquetWriteOptions options)
+    {
+        // Only the fields set by the caller are sent, the native layer keeps the session settings for the rest.
+        var proto = new Proto.ParquetWriteOptions();
+
+        if (!string.IsNullOrEmpty(options.Compression))
+            proto.Compression = options.Compression;
+
+        if (options.DictionaryEnabled.HasValue)
+            proto.DictionaryEnabled = options.DictionaryEnabled.Value;
+
+        if (!string.IsNullOrEmpty(options.StatisticsEnabled))
+            proto.StatisticsEnabled = options.StatisticsEnabled;
+
+        if (options.MaxRowGroupSize.HasValue)
+            proto.MaxRowGroupSize = options.MaxRowGroupSize.Value;
+
+        if (options.DataPageSizeLimit.HasValue)
+            proto.DataPageSizeLimit = options.DataPageSizeLimit.Value;
+
+        if (options.DataPageRowCountLimit.HasValue)
+            proto.DataPageRowCountLimit = options.DataPageRowCountLimit.Value;
+
+        if (options.DictionaryPageSizeLimit.HasValue)
+            proto.DictionaryPageSizeLimit = options.DictionaryPageSizeLimit.Value;
+
+        if (options.WriteBatchSize.HasValue)
+            proto.WriteBatchSize = options.WriteBatchSize.Value;
+
+        if (!string.IsNullOrEmpty(options.WriterVersion))
+            proto.WriterVersion = options.WriterVersion;
+
+        if (!string.IsNullOrEmpty(options.Encoding))
+            proto.Encoding = options.Encoding;
+
+        if (options.BloomFilterOnWrite.HasValue)
+            proto.BloomFilterOnWrite = options.BloomFilterOnWrite.Value;
+
+        if (options.BloomFilterFpp.HasValue)
+            proto.BloomFilterFpp = options.BloomFilterFpp.Value;
+
+        if (options.BloomFilterNdv.HasValue)
+            proto.BloomFilterNdv = options.BloomFilterNdv.Value;
+
+        if (options.CreatedBy is not null)
+            proto.CreatedBy = options.CreatedBy;
+
+        if (options.KeyValueMetadata is { Count: > 0 })
+            proto.KeyValueMetadata.Add(options.KeyValueMetadata);
+
+        if (options.ColumnOptions is { Count: > 0 })
+        {
+            foreach (var (columnName, columnOptions) in options.ColumnOptions)
+            {
+                proto.ColumnOptions.Add(new Proto.ParquetColumnSpecificOptions
+                {
+                    ColumnName = columnName,
+                    Options = columnOptions.ToProto()
+                });
+            }
+        }
+
+        return proto;
+    }
+
+    private static Proto.ParquetColumnOptions ToProto(this ParquetColumnOptions options)
+    {
+        var proto = new Proto.ParquetColumnOptions();
+
+        if (!string.IsNullOrEmpty(options.Compression))
+            proto.Compression = options.Compression;
+
+        if (options.DictionaryEnabled.HasValue)
+            proto.DictionaryEnabled = options.DictionaryEnabled.Value;
+
+        if (!string.IsNullOrEmpty(options.StatisticsEnabled))
+            proto.StatisticsEnabled = options.StatisticsEnabled;
+
+        if (!string.IsNullOrEmpty(options.Encoding))
+            proto.Encoding = options.Encoding;
+
+        if (options.BloomFilterEnabled.HasValue)
+            proto.BloomFilterEnabled = options.BloomFilterEnabled.Value;
+
+        if (options.BloomFilterFpp.HasValue)
+            proto.BloomFilterFpp = options.BloomFilterFpp.Value;
+
+        if (options.BloomFilterNdv.HasValue)
+            proto.BloomFilterNdv = options.BloomFilterNdv.Value;
+
+        return proto;
+    }
+}
@@ -0,0 +1,37 @@
+using Apache.Arrow;
+
+namespace DataFusionSharp;
+
+/// <summary>
+/// Accumulates the values of an aggregate user-defined function for one group.
+/// </summary>
+/// <remarks>
+/// An accumulator is created for every group and partition of a query; partial accumulators are combined through
+/// their intermediate state. Different accumulators may be used concurrently, a single accumulator is never used concurrently.
+/// Batches passed to an accumulator are only valid during the call. An accumulator implementing <see cref="IDisposable"/>
+/// is disposed once it is no longer used.
+/// </remarks>
+public interface IAccumulator
+{
+    /// <summary>
+    /// Updates the accumulator with a batch of arguments, with one column per argument named <c>arg0</c>, <c>arg1</c>, ...
+    /// </summary>
+    /// <param name="arguments">The batch of arguments. A function without arguments receives a batch without columns.</param>
+    void Update(RecordBatch arguments);
+
+    /// <summary>
+    /// Merges the intermediate states of other accumulators, with one column per state type.
+    /// </summary>
+    /// <param name="states">The batch of states, one row per merged accumulator.</param>
+    void Merge(RecordBatch states);
+
+    /// <summary>
+    /// Returns the intermediate state of the accumulator as single-value arrays, one per state type.
+    /// </summary>
+    IReadOnlyList<IArrowArray> GetState();
+
+    /// <summary>
+    /// Returns the aggregated value as a single-value array of the return type.
+    /// </summary>
+    IArrowArray Evaluate();
+}
@@ -9,6 +9,7 @@ internal sealed class AsyncOperations
     private sealed record Operation(object TaskCompletionSource, object? UserData);
     
     private readonly ConcurrentDictionary<ulong, object> _operations = new();
+    private readonly ConcurrentDictionary<ulong, CancellationTokenRegistration> _cancellations = new();
     private ulong _nextId;
 
     public (ulong Id, TaskCompletionSource TaskCompletionSource) Create() => Create<object>(null);
@@ -46,14 +47,45 @@ internal sealed class AsyncOperations
         return default;
     }
 
+    /// <summary>
+    /// Cancels the native operation when <paramref name="cancellationToken"/> is cancelled.
+    /// The operation then completes with an <see cref="OperationCanceledException"/>.
+    /// </summary>
+    public void RegisterCancellation(ulong id, CancellationToken cancellationToken)
+    {
+        if (!cancellationToken.CanBeCanceled)
+            return;
+
+        var registration = cancellationToken.Register(static state => NativeMethods.OperationCancel((ulong) state!), id);
+        _cancellations[id] = registration;
+
+        // The operation may have completed before the registration was stored
+        if (!_operations.ContainsKey(id))
+            ReleaseCancellation(id);
+    }
+
     public void Abort(ulong id)
     {
         _operations.TryRemove(id, out _);
+        ReleaseCancellation(id);
+    }
+
+    private bool TryRemove(ulong id, out object? operation)
+    {
+        var removed = _operations.TryRemove(id, out operation);
+        ReleaseCancellation(id);
+        return removed;
+    }
+
+    private void ReleaseCancellation(ulong id)
+    {
+        if (_cancellations.TryRemove(id, out var registration))
+            registration.Dispose();
     }
     
     public void CompleteVoid(ulong id, Exception? exception = null)
     {
-        if (!_operations.TryRemove(id, out var t) || t is not Operation op)
+        if (!TryRemove(id, out var t) || t is not Operation op)
             return;
         
         if (op.TaskCompletionSource is not TaskCompletionSource tcs)
@@ -67,7 +99,7 @@ internal sealed class AsyncOperations
     
     public void CompleteWithError<TResult>(ulong id, Exception exception)
     {
-        if (!_operations.TryRemove(id, out var t) || t is not Operation op)
+        if (!TryRemove(id, out var t) || t is not Operation op)
             return;
         
         if (op.TaskCompletionSource is not TaskCompletionSource<TResult> tcs)
@@ -78,7 +110,7 @@ internal sealed class AsyncOperations
     
     public void CompleteWithResult<TResult>(ulong id, TResult result)
     {
-        if (!_operations.TryRemove(id, out var t) || t is not Operation op)
+        if (!TryRemove(id, out var t) || t is not Operation op)
             return;
         
         if (op.TaskCompletionSource is not TaskCompletionSource<TResult> tcs)
@@ -24,6 +24,12 @@ internal struct ErrorInfoData
     public Exception ToException()
     {
         var message = Message.ToUtf8String();
-        return new DataFusionException(Code, message);
+        var exception = new DataFusionException(Code, message);
+        return Code switch
+        {
+            DataFusionErrorCode.Cancelled => new OperationCanceledException(message, exception),
+            DataFusionErrorCode.Timeout => new TimeoutException(message, exception),
+            _ => exception
+        };
     }
 }
\ No newline at end of file
@@ -0,0 +1,221 @@
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using System.Text;
+using Apache.Arrow;
+using Apache.Arrow.C;
+using Apache.Arrow.Ipc;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Interop;
+
+/// <summary>
+/// Native entry points of user-defined functions. The user data passed by the native side is a <see cref="GCHandle"/>
+/// to the managed function, released through <see cref="ReleaseHandle"/> once the native side drops the function.
+/// </summary>
+internal static unsafe class FunctionCallbacks
+{
+    /// <summary>
+    /// Message of the last failed callback on the current thread. The native side reads it right after the callback returns.
+    /// </summary>
+    [ThreadStatic]
+    private static IntPtr t_errorMessage;
+
+    public static readonly IntPtr ArrayFunctionHandle = (IntPtr)(delegate* unmanaged[Cdecl]<CArrowArray*, CArrowSchema*, CArrowArray*, CArrowSchema*, BytesData*, ulong, DataFusionErrorCode>)&InvokeArrayFunction;
+
+    public static readonly IntPtr TableFunctionHandle = (IntPtr)(delegate* unmanaged[Cdecl]<CArrowArray*, CArrowSchema*, CArrowArrayStream*, BytesData*, ulong, DataFusionErrorCode>)&InvokeTableFunction;
+
+    public static readonly IntPtr ReleaseHandle = (IntPtr)(delegate* unmanaged[Cdecl]<ulong, void>)&Release;
+
+    public static readonly NativeAccumulatorCallbacks AccumulatorCallbacks = new()
+    {
+        Create = (IntPtr)(delegate* unmanaged[Cdecl]<ulong*, BytesData*, ulong, DataFusionErrorCode>)&CreateAccumulator,
+        UpdateBatch = (IntPtr)(delegate* unmanaged[Cdecl]<CArrowArray*, CArrowSchema*, BytesData*, ulong, DataFusionErrorCode>)&UpdateAccumulator,
+        MergeBatch = (IntPtr)(delegate* unmanaged[Cdecl]<CArrowArray*, CArrowSchema*, BytesData*, ulong, DataFusionErrorCode>)&MergeAccumulator,
+        State = (IntPtr)(delegate* unmanaged[Cdecl]<CArrowArray*, CArrowSchema*, BytesData*, ulong, DataFusionErrorCode>)&GetAccumulatorState,
+        Evaluate = (IntPtr)(delegate* unmanaged[Cdecl]<CArrowArray*, CArrowSchema*, BytesData*, ulong, DataFusionErrorCode>)&EvaluateAccumulator,
+        ReleaseAccumulator = (IntPtr)(delegate* unmanaged[Cdecl]<ulong, void>)&ReleaseAccumulator
+    };
+
+    /// <summary>
+    /// Keeps <paramref name="target"/> alive for the native side and returns the user data identifying it.
+    /// </summary>
+    public static ulong Allocate(object target) => (ulong)GCHandle.ToIntPtr(GCHandle.Alloc(target));
+
+    /// <summary>
+    /// Frees a handle returned by <see cref="Allocate"/> that was not passed to the native side.
+    /// </summary>
+    public static void Free(ulong userData) => GCHandle.FromIntPtr((IntPtr)userData).Free();
+
+    private static T Target<T>(ulong userData) => (T)GCHandle.FromIntPtr((IntPtr)userData).Target!;
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode InvokeArrayFunction(CArrowArray* argsArray, CArrowSchema* argsSchema, CArrowArray* resultArray, CArrowSchema* resultSchema, BytesData* errorMessage, ulong userData)
+    {
+        try
+        {
+            var function = Target<Func<RecordBatch, IArrowArray>>(userData);
+            using var args = ImportArguments(argsArray, argsSchema);
+            ExportResult(function(args), resultArray, resultSchema);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return Fail(ex, errorMessage);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode InvokeTableFunction(CArrowArray* argsArray, CArrowSchema* argsSchema, CArrowArrayStream* streamOut, BytesData* errorMessage, ulong userData)
+    {
+        try
+        {
+            var function = Target<Func<RecordBatch, IArrowArrayStream>>(userData);
+            IArrowArrayStream stream;
+            using (var args = ImportArguments(argsArray, argsSchema))
+                stream = function(args);
+
+            ArgumentNullException.ThrowIfNull(stream);
+            CArrowArrayStreamExporter.ExportArrayStream(stream, streamOut);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return Fail(ex, errorMessage);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static void Release(ulong userData)
+    {
+        Free(userData);
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode CreateAccumulator(ulong* accumulator, BytesData* errorMessage, ulong userData)
+    {
+        try
+        {
+            var factory = Target<Func<IAccumulator>>(userData);
+            *accumulator = Allocate(factory());
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return Fail(ex, errorMessage);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode UpdateAccumulator(CArrowArray* argsArray, CArrowSchema* argsSchema, BytesData* errorMessage, ulong accumulator)
+    {
+        try
+        {
+            using var args = ImportArguments(argsArray, argsSchema);
+            Target<IAccumulator>(accumulator).Update(args);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return Fail(ex, errorMessage);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode MergeAccumulator(CArrowArray* statesArray, CArrowSchema* statesSchema, BytesData* errorMessage, ulong accumulator)
+    {
+        try
+        {
+            using var states = ImportArguments(statesArray, statesSchema);
+            Target<IAccumulator>(accumulator).Merge(states);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return Fail(ex, errorMessage);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode GetAccumulatorState(CArrowArray* resultArray, CArrowSchema* resultSchema, BytesData* errorMessage, ulong accumulator)
+    {
+        try
+        {
+            var states = Target<IAccumulator>(accumulator).GetState();
+            var fields = states.Select((state, i) => new Field($"state{i}", state.Data.DataType, nullable: true)).ToList();
+            var length = states.Count > 0 ? states[0].Length : 1;
+            ExportResult(new StructArray(new StructType(fields), length, states, ArrowBuffer.Empty), resultArray, resultSchema);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return Fail(ex, errorMessage);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static DataFusionErrorCode EvaluateAccumulator(CArrowArray* resultArray, CArrowSchema* resultSchema, BytesData* errorMessage, ulong accumulator)
+    {
+        try
+        {
+            ExportResult(Target<IAccumulator>(accumulator).Evaluate(), resultArray, resultSchema);
+            return DataFusionErrorCode.Ok;
+        }
+        catch (Exception ex)
+        {
+            return Fail(ex, errorMessage);
+        }
+    }
+
+    [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+    private static void ReleaseAccumulator(ulong accumulator)
+    {
+        var handle = GCHandle.FromIntPtr((IntPtr)accumulator);
+        var target = handle.Target;
+        handle.Free();
+
+        try
+        {
+            (target as IDisposable)?.Dispose();
+        }
+        catch
+        {
+            // An exception must not cross the native boundary, and the accumulator is no longer used anyway.
+        }
+    }
+
+    /// <summary>
+    /// Imports the arguments of a call, a struct array with one column per argument, as a record batch.
+    /// </summary>
+    internal static RecordBatch ImportArguments(CArrowArray* argsArray, CArrowSchema* argsSchema)
+    {
+        var schema = CArrowSchemaImporter.ImportSchema(argsSchema);
+        return CArrowArrayImporter.ImportRecordBatch(argsArray, schema);
+    }
+
+    /// <summary>
+    /// Exports an array returned by a managed function, transferring its ownership to the native side.
+    /// </summary>
+    internal static void ExportResult(IArrowArray result, CArrowArray* resultArray, CArrowSchema* resultSchema)
+    {
+        ArgumentNullException.ThrowIfNull(result);
+
+        CArrowSchemaExporter.ExportType(result.Data.DataType, resultSchema);
+        CArrowArrayExporter.ExportArray(result, resultArray);
+    }
+
+    /// <summary>
+    /// Reports an exception thrown by a managed function to the native side.
+    /// </summary>
+    internal static DataFusionErrorCode Fail(Exception exception, BytesData* errorMessage)
+    {
+        var bytes = Encoding.UTF8.GetBytes($"{exception.GetType().Name}: {exception.Message}");
+
+        NativeMemory.Free((void*)t_errorMessage);
+        t_errorMessage = (IntPtr)NativeMemory.Alloc((nuint)bytes.Length);
+        bytes.CopyTo(new Span<byte>((void*)t_errorMessage, bytes.Length));
+
+        *errorMessage = new BytesData { DataPtr = t_errorMessage, Length = bytes.Length };
+
+        return exception is DataFusionException dataFusionException ? dataFusionException.ErrorCode : DataFusionErrorCode.Panic;
+    }
+}
@@ -1,3 +1,5 @@
+using System.Runtime.InteropServices;
+
 namespace DataFusionSharp.Interop;
 
 internal static partial class GenericCallbacks
@@ -9,6 +11,19 @@ internal static partial class GenericCallbacks
         AsyncOperations.Instance.CompleteVoid(handle, ex);
     }
 
+    [DataFusionSharpNativeCallback]
+    internal static void CallbackForBool(IntPtr result, IntPtr error, ulong handle)
+    {
+        if (error != IntPtr.Zero)
+        {
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            AsyncOperations.Instance.CompleteWithError<bool>(handle, ex);
+            return;
+        }
+
+        AsyncOperations.Instance.CompleteWithResult(handle, Marshal.ReadByte(result) != 0);
+    }
+
     [DataFusionSharpNativeCallback]
     internal static void CallbackForString(IntPtr result, IntPtr error, ulong handle)
     {
@@ -23,4 +38,18 @@ internal static partial class GenericCallbacks
         var dataStr = data.ToUtf8String();
         AsyncOperations.Instance.CompleteWithResult(handle, dataStr);
     }
+
+    [DataFusionSharpNativeCallback]
+    internal static void CallbackForBytes(IntPtr result, IntPtr error, ulong handle)
+    {
+        if (error != IntPtr.Zero)
+        {
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            AsyncOperations.Instance.CompleteWithError<byte[]>(handle, ex);
+            return;
+        }
+
+        var data = BytesData.FromIntPtr(result);
+        AsyncOperations.Instance.CompleteWithResult(handle, data.ToArray());
+    }
 }
\ No newline at end of file
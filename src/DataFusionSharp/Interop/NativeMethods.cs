@@ -18,11 +18,14 @@ internal static partial class NativeMethods
 
     [LibraryImport(LibraryName, EntryPoint = "datafusion_runtime_destroy")]
     public static partial DataFusionErrorCode RuntimeDestroy(IntPtr runtimeHandle);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_operation_cancel")]
+    public static partial DataFusionErrorCode OperationCancel(ulong userData);
     
     // Context
     
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_new")]
-    public static partial DataFusionErrorCode ContextNew(RuntimeSafeHandle runtimeHandle, out IntPtr contextHandle);
+    public static partial DataFusionErrorCode ContextNew(RuntimeSafeHandle runtimeHandle, BytesData configData, BytesData runtimeEnvData, out IntPtr contextHandle);
     
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_destroy")]
     public static partial DataFusionErrorCode ContextDestroy(IntPtr contextHandle);
@@ -34,13 +37,108 @@ internal static partial class NativeMethods
     public static partial DataFusionErrorCode ContextRegisterJson(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName, [MarshalAs(UnmanagedType.LPUTF8Str)] string filePath, BytesData optionsData, IntPtr callback, ulong userData);
     
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_parquet")]
-    public static partial DataFusionErrorCode ContextRegisterParquet(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName, [MarshalAs(UnmanagedType.LPUTF8Str)] string filePath, IntPtr callback, ulong userData);
+    public static partial DataFusionErrorCode ContextRegisterParquet(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName, [MarshalAs(UnmanagedType.LPUTF8Str)] string filePath, BytesData optionsData, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_arrow")]
+    public static partial DataFusionErrorCode ContextRegisterArrow(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName, [MarshalAs(UnmanagedType.LPUTF8Str)] string filePath, BytesData optionsData, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_batches")]
+    public static unsafe partial DataFusionErrorCode ContextRegisterBatches(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName, Apache.Arrow.C.CArrowSchema* schema, Apache.Arrow.C.CArrowArray* batches, int numBatches, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_stream")]
+    public static unsafe partial DataFusionErrorCode ContextRegisterStream(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName, Apache.Arrow.C.CArrowArrayStream* stream, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_dataframe_view")]
+    public static partial DataFusionErrorCode ContextRegisterDataFrameView(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName, DataFrameSafeHandle dataFrameHandle, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_dataframe_table")]
+    public static partial DataFusionErrorCode ContextRegisterDataFrameTable(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName, DataFrameSafeHandle dataFrameHandle, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_read_csv")]
+    public static partial DataFusionErrorCode ContextReadCsv(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string filePath, BytesData optionsData, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_read_json")]
+    public static partial DataFusionErrorCode ContextReadJson(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string filePath, BytesData optionsData, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_read_parquet")]
+    public static partial DataFusionErrorCode ContextReadParquet(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string filePath, BytesData optionsData, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_read_arrow")]
+    public static partial DataFusionErrorCode ContextReadArrow(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string filePath, BytesData optionsData, IntPtr callback, ulong userData);
     
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_deregister_table")]
     public static partial DataFusionErrorCode ContextDeregisterTable(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableName, IntPtr callback, ulong userData);
     
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_create_catalog")]
+    public static partial DataFusionErrorCode ContextCreateCatalog(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_drop_catalog")]
+    public static partial DataFusionErrorCode ContextDropCatalog(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName, [MarshalAs(UnmanagedType.U1)] bool cascade, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_create_schema")]
+    public static partial DataFusionErrorCode ContextCreateSchema(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName, [MarshalAs(UnmanagedType.LPUTF8Str)] string schemaName, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_drop_schema")]
+    public static partial DataFusionErrorCode ContextDropSchema(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName, [MarshalAs(UnmanagedType.LPUTF8Str)] string schemaName, [MarshalAs(UnmanagedType.U1)] bool cascade, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_list_catalogs")]
+    public static partial DataFusionErrorCode ContextListCatalogs(SessionContextSafeHandle contextHandle, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_list_schemas")]
+    public static partial DataFusionErrorCode ContextListSchemas(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_list_tables")]
+    public static partial DataFusionErrorCode ContextListTables(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string catalogName, [MarshalAs(UnmanagedType.LPUTF8Str)] string schemaName, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_table_exists")]
+    public static partial DataFusionErrorCode ContextTableExists(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string tableReference, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_set_option")]
+    public static partial DataFusionErrorCode ContextSetOption(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string key, [MarshalAs(UnmanagedType.LPUTF8Str)] string value, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_get_options")]
+    public static partial DataFusionErrorCode ContextGetOptions(SessionContextSafeHandle contextHandle, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_set_dialect")]
+    public static partial DataFusionErrorCode ContextSetDialect(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string dialect, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_scalar_udf")]
+    public static partial DataFusionErrorCode ContextRegisterScalarUdf(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string name, BytesData signatureData, IntPtr invoke, IntPtr release, ulong functionUserData, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_aggregate_udf")]
+    public static partial DataFusionErrorCode ContextRegisterAggregateUdf(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string name, BytesData signatureData, in NativeAccumulatorCallbacks accumulatorCallbacks, IntPtr release, ulong functionUserData, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_window_udf")]
+    public static partial DataFusionErrorCode ContextRegisterWindowUdf(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string name, BytesData signatureData, IntPtr evaluate, IntPtr release, ulong functionUserData, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_register_table_function")]
+    public static partial DataFusionErrorCode ContextRegisterTableFunction(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string name, IntPtr invoke, IntPtr release, ulong functionUserData, IntPtr callback, ulong userData);
+    
     [LibraryImport(LibraryName, EntryPoint = "datafusion_context_sql")]
-    public static partial DataFusionErrorCode ContextSql(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string sql, BytesData sqlParametersData, IntPtr callback, ulong userData); 
+    public static partial DataFusionErrorCode ContextSql(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string sql, BytesData sqlParametersData, ulong timeoutMillis, IntPtr callback, ulong userData); 
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_sql_with_options")]
+    public static partial DataFusionErrorCode ContextSqlWithOptions(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string sql, BytesData sqlParametersData, BytesData sqlOptionsData, ulong timeoutMillis, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_sql_script")]
+    public static partial DataFusionErrorCode ContextSqlScript(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string sql, BytesData sqlOptionsData, ulong timeoutMillis, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_sql_parameter_types")]
+    public static partial DataFusionErrorCode ContextSqlParameterTypes(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string sql, BytesData sqlOptionsData, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_context_prepare")]
+    public static partial DataFusionErrorCode ContextPrepare(SessionContextSafeHandle contextHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string sql, BytesData sqlOptionsData, IntPtr callback, ulong userData);
+    
+    // Prepared statement
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_prepared_statement_destroy")]
+    public static partial DataFusionErrorCode PreparedStatementDestroy(IntPtr statementHandle);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_prepared_statement_execute")]
+    public static partial DataFusionErrorCode PreparedStatementExecute(PreparedStatementSafeHandle statementHandle, BytesData sqlParametersData, IntPtr callback, ulong userData);
+    
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_prepared_statement_parameter_types")]
+    public static partial DataFusionErrorCode PreparedStatementParameterTypes(PreparedStatementSafeHandle statementHandle, IntPtr callback, ulong userData);
 
     // DataFrame
     
@@ -57,19 +155,78 @@ internal static partial class NativeMethods
     public static partial DataFusionErrorCode DataFrameSchema(DataFrameSafeHandle dataFrameHandle, IntPtr callback, ulong userData);
     
     [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_collect")]
-    public static partial DataFusionErrorCode DataFrameCollect(DataFrameSafeHandle dataFrameHandle, IntPtr callback, ulong userData);
+    public static partial DataFusionErrorCode DataFrameCollect(DataFrameSafeHandle dataFrameHandle, ulong timeoutMillis, IntPtr callback, ulong userData);
 
     [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_to_string")]
     public static partial DataFusionErrorCode DataFrameToString(DataFrameSafeHandle dataFrameHandle, IntPtr callback, ulong userData);
 
     [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_write_csv")]
-    public static partial DataFusionErrorCode DataFrameWriteCsv(DataFrameSafeHandle dataFrameHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string path, BytesData dataFrameWriteOptionsData, BytesData csvWriteOptionsData, IntPtr callback, ulong userData);
+    public static partial DataFusionErrorCode DataFrameWriteCsv(DataFrameSafeHandle dataFrameHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string path, BytesData dataFrameWriteOptionsData, BytesData csvWriteOptionsData, ulong timeoutMillis, IntPtr callback, ulong userData);
 
     [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_write_json")]
-    public static partial DataFusionErrorCode DataFrameWriteJson(DataFrameSafeHandle dataFrameHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string path, BytesData dataFrameWriteOptionsData, BytesData jsonWriteOptionsData, IntPtr callback, ulong userData);
+    public static partial DataFusionErrorCode DataFrameWriteJson(DataFrameSafeHandle dataFrameHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string path, BytesData dataFrameWriteOptionsData, BytesData jsonWriteOptionsData, ulong timeoutMillis, IntPtr callback, ulong userData);
 
     [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_write_parquet")]
-    public static partial DataFusionErrorCode DataFrameWriteParquet(DataFrameSafeHandle dataFrameHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string path, IntPtr callback, ulong userData);
+    public static partial DataFusionErrorCode DataFrameWriteParquet(DataFrameSafeHandle dataFrameHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string path, BytesData dataFrameWriteOptionsData, BytesData parquetWriteOptionsData, ulong timeoutMillis, IntPtr callback, ulong userData);
+
+    // Transformations
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_select")]
+    public static partial DataFusionErrorCode DataFrameSelect(DataFrameSafeHandle dataFrameHandle, BytesData exprsData, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_filter")]
+    public static partial DataFusionErrorCode DataFrameFilter(DataFrameSafeHandle dataFrameHandle, BytesData predicateData, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_aggregate")]
+    public static partial DataFusionErrorCode DataFrameAggregate(DataFrameSafeHandle dataFrameHandle, BytesData aggregateData, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_sort")]
+    public static partial DataFusionErrorCode DataFrameSort(DataFrameSafeHandle dataFrameHandle, BytesData sortExprsData, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_limit")]
+    public static partial DataFusionErrorCode DataFrameLimit(DataFrameSafeHandle dataFrameHandle, ulong skip, long fetch, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_with_column")]
+    public static partial DataFusionErrorCode DataFrameWithColumn(DataFrameSafeHandle dataFrameHandle, [MarshalAs(UnmanagedType.LPUTF8Str)] string name, BytesData exprData, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_drop_columns")]
+    public static partial DataFusionErrorCode DataFrameDropColumns(DataFrameSafeHandle dataFrameHandle, BytesData columnsData, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_join")]
+    public static partial DataFusionErrorCode DataFrameJoin(DataFrameSafeHandle leftHandle, DataFrameSafeHandle rightHandle, BytesData joinOptionsData, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_cross_join")]
+    public static partial DataFusionErrorCode DataFrameCrossJoin(DataFrameSafeHandle leftHandle, DataFrameSafeHandle rightHandle, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_union")]
+    public static partial DataFusionErrorCode DataFrameUnion(DataFrameSafeHandle leftHandle, DataFrameSafeHandle rightHandle, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_union_distinct")]
+    public static partial DataFusionErrorCode DataFrameUnionDistinct(DataFrameSafeHandle leftHandle, DataFrameSafeHandle rightHandle, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_intersect")]
+    public static partial DataFusionErrorCode DataFrameIntersect(DataFrameSafeHandle leftHandle, DataFrameSafeHandle rightHandle, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_intersect_distinct")]
+    public static partial DataFusionErrorCode DataFrameIntersectDistinct(DataFrameSafeHandle leftHandle, DataFrameSafeHandle rightHandle, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_except")]
+    public static partial DataFusionErrorCode DataFrameExcept(DataFrameSafeHandle leftHandle, DataFrameSafeHandle rightHandle, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_except_distinct")]
+    public static partial DataFusionErrorCode DataFrameExceptDistinct(DataFrameSafeHandle leftHandle, DataFrameSafeHandle rightHandle, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_distinct")]
+    public static partial DataFusionErrorCode DataFrameDistinct(DataFrameSafeHandle dataFrameHandle, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_distinct_on")]
+    public static partial DataFusionErrorCode DataFrameDistinctOn(DataFrameSafeHandle dataFrameHandle, BytesData distinctOnData, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_describe")]
+    public static partial DataFusionErrorCode DataFrameDescribe(DataFrameSafeHandle dataFrameHandle, ulong timeoutMillis, IntPtr callback, ulong userData);
+
+    [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_cache")]
+    public static partial DataFusionErrorCode DataFrameCache(DataFrameSafeHandle dataFrameHandle, ulong memoryLimit, ulong timeoutMillis, IntPtr callback, ulong userData);
 
     // Stream
 
@@ -80,5 +237,5 @@ internal static partial class NativeMethods
     public static partial DataFusionErrorCode DataFrameStreamDestroy(IntPtr streamHandle);
 
     [LibraryImport(LibraryName, EntryPoint = "datafusion_dataframe_stream_next")]
-    public static partial DataFusionErrorCode DataFrameStreamNext(DataFrameStreamSafeHandle streamHandle, IntPtr callback, ulong userData);
+    public static partial DataFusionErrorCode DataFrameStreamNext(DataFrameStreamSafeHandle streamHandle, ulong timeoutMillis, IntPtr callback, ulong userData);
 }
\ No newline at end of file
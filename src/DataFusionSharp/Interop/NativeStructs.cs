@@ -15,4 +15,15 @@ internal unsafe struct NativeDataFrameExecutedStreamData
 {
     public IntPtr StreamHandle;
     public Apache.Arrow.C.CArrowSchema* Schema;
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal struct NativeAccumulatorCallbacks
+{
+    public IntPtr Create;
+    public IntPtr UpdateBatch;
+    public IntPtr MergeBatch;
+    public IntPtr State;
+    public IntPtr Evaluate;
+    public IntPtr ReleaseAccumulator;
 }
\ No newline at end of file
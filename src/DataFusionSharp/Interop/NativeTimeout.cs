@@ -0,0 +1,20 @@
+using System.Runtime.CompilerServices;
+
+namespace DataFusionSharp.Interop;
+
+internal static class NativeTimeout
+{
+    /// <summary>
+    /// Converts an optional timeout to the milliseconds expected by native operations, where 0 means no timeout.
+    /// </summary>
+    public static ulong ToMillis(TimeSpan? timeout, [CallerArgumentExpression(nameof(timeout))] string? paramName = null)
+    {
+        if (timeout is not { } value || value == Timeout.InfiniteTimeSpan)
+            return 0;
+
+        if (value <= TimeSpan.Zero)
+            throw new ArgumentOutOfRangeException(paramName, value, "Timeout must be positive or infinite");
+
+        return (ulong) Math.Ceiling(value.TotalMilliseconds);
+    }
+}
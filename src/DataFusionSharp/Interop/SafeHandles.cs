@@ -61,6 +61,19 @@ internal sealed class SessionContextSafeHandle : DataFusionSafeHandle
     }
 }
 
+internal sealed class PreparedStatementSafeHandle : DataFusionSafeHandle
+{
+    internal PreparedStatementSafeHandle(IntPtr handle)
+        : base(handle)
+    {
+    }
+
+    protected override bool ReleaseHandle()
+    {
+        return NativeMethods.PreparedStatementDestroy(handle) == DataFusionErrorCode.Ok;
+    }
+}
+
 internal sealed class DataFrameSafeHandle : DataFusionSafeHandle
 {
     internal DataFrameSafeHandle(IntPtr handle)
@@ -0,0 +1,40 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// Kind of join between two DataFrames.
+/// </summary>
+public enum JoinType
+{
+    /// <summary>Returns the pairs of rows that match on both sides.</summary>
+    Inner,
+    /// <summary>Returns all rows of the left side, with nulls for the right columns where nothing matches.</summary>
+    Left,
+    /// <summary>Returns all rows of the right side, with nulls for the left columns where nothing matches.</summary>
+    Right,
+    /// <summary>Returns all rows of both sides, with nulls for the columns of the side where nothing matches.</summary>
+    Full,
+    /// <summary>Returns the rows of the left side that have a match on the right side, with the left columns only.</summary>
+    LeftSemi,
+    /// <summary>Returns the rows of the left side that have no match on the right side, with the left columns only.</summary>
+    LeftAnti,
+    /// <summary>Returns the rows of the right side that have a match on the left side, with the right columns only.</summary>
+    RightSemi,
+    /// <summary>Returns the rows of the right side that have no match on the left side, with the right columns only.</summary>
+    RightAnti
+}
+
+internal static class ProtoJoinTypeExtensions
+{
+    internal static Proto.JoinType ToProto(this JoinType joinType) => joinType switch
+    {
+        JoinType.Inner => Proto.JoinType.Inner,
+        JoinType.Left => Proto.JoinType.Left,
+        JoinType.Right => Proto.JoinType.Right,
+        JoinType.Full => Proto.JoinType.Full,
+        JoinType.LeftSemi => Proto.JoinType.Leftsemi,
+        JoinType.LeftAnti => Proto.JoinType.Leftanti,
+        JoinType.RightSemi => Proto.JoinType.Rightsemi,
+        JoinType.RightAnti => Proto.JoinType.Rightanti,
+        _ => throw new ArgumentOutOfRangeException(nameof(joinType), joinType, "Invalid JoinType value")
+    };
+}
@@ -0,0 +1,14 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// Memory pool used to limit memory of query execution.
+/// </summary>
+public enum MemoryPoolType
+{
+    /// <summary>No memory limit.</summary>
+    Unbounded,
+    /// <summary>First-come first-served pool; a single operator may use the whole limit.</summary>
+    Greedy,
+    /// <summary>Pool that splits the limit fairly between operators that can spill to disk.</summary>
+    Fair
+}
@@ -0,0 +1,123 @@
+using DataFusionSharp.Interop;
+
+namespace DataFusionSharp;
+
+/// <summary>
+/// A SQL query that is parsed and planned once and executed repeatedly with different parameters.
+/// </summary>
+/// <remarks>
+/// Create a prepared statement with <see cref="SessionContext.PrepareAsync(string, CancellationToken)"/>.
+/// It is important to dispose of the <see cref="PreparedStatement"/> when it is no longer needed to free the native resources.
+/// This class is not thread-safe. Do not call methods on the same instance concurrently from multiple threads.
+/// </remarks>
+public sealed class PreparedStatement : IDisposable
+{
+    private readonly PreparedStatementSafeHandle _handle;
+
+    /// <summary>
+    /// Gets the session context that prepared this statement.
+    /// </summary>
+    public SessionContext Context { get; }
+
+    internal PreparedStatement(SessionContext sessionContext, PreparedStatementSafeHandle handle)
+    {
+        Context = sessionContext;
+        _handle = handle;
+    }
+
+    /// <summary>
+    /// Executes the statement without parameters and returns the result as a DataFrame.
+    /// </summary>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when execution fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task<DataFrame> ExecuteAsync(CancellationToken cancellationToken = default) => ExecuteAsync((Proto.SqlParameters?) null, cancellationToken);
+
+    /// <summary>
+    /// Binds named parameters to the statement and returns the result as a DataFrame.
+    /// </summary>
+    /// <param name="parameters">Named parameters to bind to the placeholders of the statement.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when binding or execution fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task<DataFrame> ExecuteAsync(IEnumerable<SqlNamedParameter> parameters, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(parameters);
+
+        var parametersProto = new Proto.SqlParameters();
+        foreach (var param in parameters)
+            parametersProto.Values.Add(param.Name, param.ProtoValue);
+
+        return ExecuteAsync(parametersProto, cancellationToken);
+    }
+
+    /// <summary>
+    /// Binds positional parameters to the statement and returns the result as a DataFrame.
+    /// </summary>
+    /// <param name="parameters">Positional parameters to bind to the placeholders of the statement, in placeholder order.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when binding or execution fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task<DataFrame> ExecuteAsync(IEnumerable<SqlPositionalParameter> parameters, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(parameters);
+
+        var parametersProto = new Proto.SqlParameters();
+        foreach (var param in parameters)
+            parametersProto.PositionalValues.Add(param.ProtoValue);
+
+        return ExecuteAsync(parametersProto, cancellationToken);
+    }
+
+    /// <summary>
+    /// Returns the inferred types of the placeholders of the statement.
+    /// </summary>
+    /// <returns>
+    /// A task containing the placeholders of the statement. Positional placeholders come first in numeric order, then named ones by name.
+    /// </returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<IReadOnlyList<SqlParameterType>> GetParameterTypesAsync()
+    {
+        var (id, tcs) = AsyncOperations.Instance.Create<byte[]>();
+        var result = NativeMethods.PreparedStatementParameterTypes(_handle, GenericCallbacks.CallbackForBytesHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start getting prepared statement parameter types");
+        }
+
+        var bytes = await tcs.Task.ConfigureAwait(false);
+        return SqlParameterType.FromProtoBytes(bytes);
+    }
+
+    /// <inheritdoc />
+    public void Dispose()
+    {
+        _handle.Dispose();
+    }
+
+    private async Task<DataFrame> ExecuteAsync(Proto.SqlParameters? parametersProto, CancellationToken cancellationToken)
+    {
+        Task<DataFrameSafeHandle> task;
+        using (var sqlParametersData = PinnedProtobufData.FromMessage(parametersProto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.PreparedStatementExecute(_handle, sqlParametersData.ToBytesData(), SessionContext.CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start executing prepared statement");
+            }
+
+            AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+            task = tcs.Task;
+        }
+
+        var dataFrameSafeHandle = await task.ConfigureAwait(false);
+        return new DataFrame(Context, dataFrameSafeHandle);
+    }
+}
@@ -1,6 +1,12 @@
 using System.Runtime.InteropServices;
+using Apache.Arrow;
+using Apache.Arrow.C;
+using Apache.Arrow.Ipc;
+using Apache.Arrow.Types;
+using DataFusionSharp.Formats.Arrow;
 using DataFusionSharp.Formats.Csv;
 using DataFusionSharp.Formats.Json;
+using DataFusionSharp.Formats.Parquet;
 using DataFusionSharp.Interop;
 
 namespace DataFusionSharp;
@@ -34,9 +40,11 @@ public sealed partial class SessionContext : IDisposable
     /// <param name="tableName">The name to use for the table.</param>
     /// <param name="filePath">The path to the CSV file.</param>
     /// <param name="options">Optional CSV read options to customize parsing behavior.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
     /// <returns>A task representing the asynchronous operation.</returns>
     /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
-    public Task RegisterCsvAsync(string tableName, string filePath, CsvReadOptions? options = null)
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task RegisterCsvAsync(string tableName, string filePath, CsvReadOptions? options = null, CancellationToken cancellationToken = default)
     {
         using var optionsData = PinnedProtobufData.FromMessage(options?.ToProto());
         
@@ -48,134 +56,1130 @@ public sealed partial class SessionContext : IDisposable
             throw new DataFusionException(result, "Failed to start registering CSV file");
         }
 
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+        return tcs.Task;
+    }
+    
+    /// <summary>
+    /// Registers a JSON file as a table in this session.
+    /// </summary>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="filePath">The path to the JSON file.</param>
+    /// <param name="options">Optional JSON read options to customize parsing behavior.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task RegisterJsonAsync(string tableName, string filePath, JsonReadOptions? options = null, CancellationToken cancellationToken = default)
+    {
+        using var optionsData = PinnedProtobufData.FromMessage(options?.ToProto());
+
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextRegisterJson(_handle, tableName, filePath, optionsData.ToBytesData(), GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start registering JSON file");
+        }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+        return tcs.Task;
+    }
+    
+    /// <summary>
+    /// Registers a Parquet file as a table in this session.
+    /// </summary>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="filePath">The path to the Parquet file.</param>
+    /// <param name="options">Optional Parquet read options to customize reading behavior.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task RegisterParquetAsync(string tableName, string filePath, ParquetReadOptions? options = null, CancellationToken cancellationToken = default)
+    {
+        using var optionsData = PinnedProtobufData.FromMessage(options?.ToProto());
+
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextRegisterParquet(_handle, tableName, filePath, optionsData.ToBytesData(), GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start registering Parquet file");
+        }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+        return tcs.Task;
+    }
+    
+    /// <summary>
+    /// Registers an Arrow IPC file as a table in this session.
+    /// </summary>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="filePath">The path to the Arrow IPC file.</param>
+    /// <param name="options">Optional Arrow read options to customize reading behavior.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task RegisterArrowAsync(string tableName, string filePath, ArrowReadOptions? options = null, CancellationToken cancellationToken = default)
+    {
+        using var optionsData = PinnedProtobufData.FromMessage(options?.ToProto());
+
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextRegisterArrow(_handle, tableName, filePath, optionsData.ToBytesData(), GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start registering Arrow file");
+        }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Registers in-memory Arrow record batches as a table in this session.
+    /// </summary>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="schema">The schema of the batches.</param>
+    /// <param name="batches">The record batches to register. All batches must match <paramref name="schema"/>.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
+    /// <remarks>
+    /// The batches are exported through the Arrow C Data Interface without copying,
+    /// so their memory is shared with the registered table.
+    /// </remarks>
+    public unsafe Task RegisterBatchesAsync(string tableName, Schema schema, IReadOnlyList<RecordBatch> batches)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+        ArgumentNullException.ThrowIfNull(schema);
+        ArgumentNullException.ThrowIfNull(batches);
+
+        var ffiSchema = CArrowSchema.Create();
+        var ffiBatches = (CArrowArray*)NativeMemory.AllocZeroed((nuint)batches.Count, (nuint)sizeof(CArrowArray));
+        try
+        {
+            CArrowSchemaExporter.ExportSchema(schema, ffiSchema);
+            for (var i = 0; i < batches.Count; i++)
+                CArrowArrayExporter.ExportRecordBatch(batches[i], ffiBatches + i);
+
+            var (id, tcs) = AsyncOperations.Instance.Create();
+            var result = NativeMethods.ContextRegisterBatches(_handle, tableName, ffiSchema, ffiBatches, batches.Count, GenericCallbacks.CallbackForVoidHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start registering record batches");
+            }
+
+            return tcs.Task;
+        }
+        finally
+        {
+            // The native side takes ownership of the exported batches, so this only releases batches it did not take.
+            for (var i = 0; i < batches.Count; i++)
+                CArrowArray.CallReleaseFunc(ffiBatches + i);
+            NativeMemory.Free(ffiBatches);
+            CArrowSchema.Free(ffiSchema);
+        }
+    }
+
+    /// <summary>
+    /// Registers an Arrow array stream as a streaming table in this session.
+    /// </summary>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="stream">The stream providing the table's record batches.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
+    /// <remarks>
+    /// Batches are pulled from <paramref name="stream"/> on demand while the table is scanned, possibly from native worker threads,
+    /// so the stream can produce its data lazily. The stream can be read by only one query: planning the execution
+    /// of a later query that scans the table throws a <see cref="DataFusionException"/> with
+    /// <see cref="DataFusionErrorCode.InvalidArgument"/>. The table stays registered until it is deregistered.
+    /// Ownership of the stream is transferred to the table, which disposes it when the table is released.
+    /// </remarks>
+    public unsafe Task RegisterStreamAsync(string tableName, IArrowArrayStream stream)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+        ArgumentNullException.ThrowIfNull(stream);
+
+        var ffiStream = CArrowArrayStream.Create();
+        try
+        {
+            CArrowArrayStreamExporter.ExportArrayStream(stream, ffiStream);
+
+            var (id, tcs) = AsyncOperations.Instance.Create();
+            var result = NativeMethods.ContextRegisterStream(_handle, tableName, ffiStream, GenericCallbacks.CallbackForVoidHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start registering Arrow stream");
+            }
+
+            return tcs.Task;
+        }
+        finally
+        {
+            // The native side takes ownership of the exported stream, so this only releases a stream it did not take.
+            CArrowArrayStream.Free(ffiStream);
+        }
+    }
+
+    /// <summary>
+    /// Registers a DataFrame as a view in this session.
+    /// </summary>
+    /// <param name="tableName">The name to use for the view.</param>
+    /// <param name="dataFrame">The DataFrame whose query defines the view. It remains usable after registration.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when view registration fails.</exception>
+    /// <remarks>The view keeps the query plan of the DataFrame, so it is re-evaluated every time it is queried.</remarks>
+    public Task RegisterViewAsync(string tableName, DataFrame dataFrame)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+        ArgumentNullException.ThrowIfNull(dataFrame);
+
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextRegisterDataFrameView(_handle, tableName, dataFrame.Handle, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start registering DataFrame view");
+        }
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Executes a DataFrame and registers its result as an in-memory table in this session.
+    /// </summary>
+    /// <param name="tableName">The name to use for the table.</param>
+    /// <param name="dataFrame">The DataFrame to materialize. It remains usable after registration.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when executing the DataFrame or table registration fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task RegisterTableAsync(string tableName, DataFrame dataFrame, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(tableName);
+        ArgumentNullException.ThrowIfNull(dataFrame);
+
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextRegisterDataFrameTable(_handle, tableName, dataFrame.Handle, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start materializing DataFrame as table");
+        }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Reads CSV file(s) into a DataFrame without registering a table.
+    /// </summary>
+    /// <param name="filePath">The path to the CSV file or a directory of CSV files.</param>
+    /// <param name="options">Optional CSV read options to customize parsing behavior.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when reading fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public async Task<DataFrame> ReadCsvAsync(string filePath, CsvReadOptions? options = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(filePath);
+
+        Task<DataFrameSafeHandle> task;
+        using (var optionsData = PinnedProtobufData.FromMessage(options?.ToProto()))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.ContextReadCsv(_handle, filePath, optionsData.ToBytesData(), CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start reading CSV file");
+            }
+
+            AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+            task = tcs.Task;
+        }
+
+        var dataFrameSafeHandle = await task.ConfigureAwait(false);
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Reads JSON file(s) into a DataFrame without registering a table.
+    /// </summary>
+    /// <param name="filePath">The path to the JSON file or a directory of JSON files.</param>
+    /// <param name="options">Optional JSON read options to customize parsing behavior.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when reading fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public async Task<DataFrame> ReadJsonAsync(string filePath, JsonReadOptions? options = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(filePath);
+
+        Task<DataFrameSafeHandle> task;
+        using (var optionsData = PinnedProtobufData.FromMessage(options?.ToProto()))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.ContextReadJson(_handle, filePath, optionsData.ToBytesData(), CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start reading JSON file");
+            }
+
+            AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+            task = tcs.Task;
+        }
+
+        var dataFrameSafeHandle = await task.ConfigureAwait(false);
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Reads Parquet file(s) into a DataFrame without registering a table.
+    /// </summary>
+    /// <param name="filePath">The path to the Parquet file or a directory of Parquet files.</param>
+    /// <param name="options">Optional Parquet read options to customize reading behavior.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when reading fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public async Task<DataFrame> ReadParquetAsync(string filePath, ParquetReadOptions? options = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(filePath);
+
+        Task<DataFrameSafeHandle> task;
+        using (var optionsData = PinnedProtobufData.FromMessage(options?.ToProto()))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.ContextReadParquet(_handle, filePath, optionsData.ToBytesData(), CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start reading Parquet file");
+            }
+
+            AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+            task = tcs.Task;
+        }
+
+        var dataFrameSafeHandle = await task.ConfigureAwait(false);
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Reads Arrow IPC file(s) into a DataFrame without registering a table.
+    /// </summary>
+    /// <param name="filePath">The path to the Arrow IPC file or a directory of Arrow IPC files.</param>
+    /// <param name="options">Optional Arrow IPC read options to customize reading behavior.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when reading fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public async Task<DataFrame> ReadArrowAsync(string filePath, ArrowReadOptions? options = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(filePath);
+
+        Task<DataFrameSafeHandle> task;
+        using (var optionsData = PinnedProtobufData.FromMessage(options?.ToProto()))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.ContextReadArrow(_handle, filePath, optionsData.ToBytesData(), CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start reading Arrow IPC file");
+            }
+
+            AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+            task = tcs.Task;
+        }
+
+        var dataFrameSafeHandle = await task.ConfigureAwait(false);
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Deregisters a table from this session.
+    /// </summary>
+    /// <param name="tableName">The name of the table to deregister.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when table deregistration fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task DeregisterTableAsync(string tableName, CancellationToken cancellationToken = default)
+    {
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextDeregisterTable(_handle, tableName, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start deregistering table");
+        }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Executes a SQL query and returns the result as a DataFrame.
+    /// </summary>
+    /// <param name="sql">The SQL query to execute.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when query execution fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <example>
+    /// <code language="csharp">
+    /// var df = await session.SqlAsync("SELECT * FROM my_table");
+    /// </code>
+    /// </example>
+    public Task<DataFrame> SqlAsync(string sql, CancellationToken cancellationToken = default) => SqlAsync(sql, (TimeSpan?) null, cancellationToken);
+
+    /// <summary>
+    /// Executes a SQL query and returns the result as a DataFrame, aborting if planning takes longer than <paramref name="timeout"/>.
+    /// </summary>
+    /// <param name="sql">The SQL query to execute.</param>
+    /// <param name="timeout">Maximum duration of the native operation. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when query execution fails.</exception>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public async Task<DataFrame> SqlAsync(string sql, TimeSpan? timeout, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(sql);
+        var timeoutMillis = NativeTimeout.ToMillis(timeout);
+        
+        var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+        var result = NativeMethods.ContextSql(_handle, sql, BytesData.Empty, timeoutMillis, CallbackForDataFrameHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start executing SQL query");
+        }
+
+        AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+        var dataFrameSafeHandle = await tcs.Task.ConfigureAwait(false);
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Executes a SQL query restricted by <paramref name="options"/> and returns the result as a DataFrame.
+    /// </summary>
+    /// <param name="sql">The SQL query to execute.</param>
+    /// <param name="options">Options that restrict the allowed statement kinds or select the SQL dialect of the query.</param>
+    /// <param name="timeout">Maximum duration of the native operation. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">
+    /// Thrown when query execution fails, with <see cref="DataFusionErrorCode.StatementNotAllowed"/> if the statement is rejected by <paramref name="options"/>.
+    /// </exception>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <example>
+    /// <code language="csharp">
+    /// var df = await session.SqlAsync(userQuery, SqlOptions.ReadOnly);
+    /// </code>
+    /// </example>
+    public Task<DataFrame> SqlAsync(string sql, SqlOptions options, TimeSpan? timeout = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(sql);
+        ArgumentNullException.ThrowIfNull(options);
+
+        return SqlAsync(sql, null, options.ToProto(), timeout, cancellationToken);
+    }
+    
+    /// <summary>
+    /// Executes a SQL query with named parameters and returns the result as a DataFrame.
+    /// </summary>
+    /// <param name="sql">The SQL query to execute, which can contain named parameter placeholders (e.g., $paramName).</param>
+    /// <param name="parameters">A named parameters to bind to the query.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when query execution fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <example>
+    /// <code language="csharp">
+    /// var df = await session.SqlAsync("SELECT * FROM my_table WHERE id = $id", [("id", 123)]);
+    /// </code>
+    /// </example>
+    public Task<DataFrame> SqlAsync(string sql, IEnumerable<SqlNamedParameter> parameters, CancellationToken cancellationToken = default) =>
+        SqlAsync(sql, parameters, null, cancellationToken);
+
+    /// <summary>
+    /// Executes a SQL query with named parameters and returns the result as a DataFrame,
+    /// aborting if planning takes longer than <paramref name="timeout"/>.
+    /// </summary>
+    /// <param name="sql">The SQL query to execute, which can contain named parameter placeholders (e.g., $paramName).</param>
+    /// <param name="parameters">A named parameters to bind to the query.</param>
+    /// <param name="timeout">Maximum duration of the native operation. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when query execution fails.</exception>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task<DataFrame> SqlAsync(string sql, IEnumerable<SqlNamedParameter> parameters, TimeSpan? timeout, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(sql);
+        ArgumentNullException.ThrowIfNull(parameters);
+        
+        var parametersProto = new Proto.SqlParameters();
+        foreach (var param in parameters)
+            parametersProto.Values.Add(param.Name, param.ProtoValue);
+
+        return SqlAsync(sql, parametersProto, null, timeout, cancellationToken);
+    }
+
+    /// <summary>
+    /// Executes a SQL query with positional parameters and returns the result as a DataFrame.
+    /// </summary>
+    /// <param name="sql">The SQL query to execute, which can contain positional parameter placeholders ($1, $2, ...).</param>
+    /// <param name="parameters">Positional parameters to bind to the query, in placeholder order.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when query execution fails.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <example>
+    /// <code language="csharp">
+    /// var df = await session.SqlAsync("SELECT * FROM my_table WHERE id = $1", [new SqlPositionalParameter(123)]);
+    /// </code>
+    /// </example>
+    public Task<DataFrame> SqlAsync(string sql, IEnumerable<SqlPositionalParameter> parameters, CancellationToken cancellationToken = default) =>
+        SqlAsync(sql, parameters, null, cancellationToken);
+
+    /// <summary>
+    /// Executes a SQL query with positional parameters and returns the result as a DataFrame,
+    /// aborting if planning takes longer than <paramref name="timeout"/>.
+    /// </summary>
+    /// <param name="sql">The SQL query to execute, which can contain positional parameter placeholders ($1, $2, ...).</param>
+    /// <param name="parameters">Positional parameters to bind to the query, in placeholder order.</param>
+    /// <param name="timeout">Maximum duration of the native operation. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when query execution fails.</exception>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task<DataFrame> SqlAsync(string sql, IEnumerable<SqlPositionalParameter> parameters, TimeSpan? timeout, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(sql);
+        ArgumentNullException.ThrowIfNull(parameters);
+        
+        var parametersProto = new Proto.SqlParameters();
+        foreach (var param in parameters)
+            parametersProto.PositionalValues.Add(param.ProtoValue);
+
+        return SqlAsync(sql, parametersProto, null, timeout, cancellationToken);
+    }
+
+    private async Task<DataFrame> SqlAsync(string sql, Proto.SqlParameters? parametersProto, Proto.SqlOptions? optionsProto, TimeSpan? timeout, CancellationToken cancellationToken)
+    {
+        var timeoutMillis = NativeTimeout.ToMillis(timeout);
+
+        Task<DataFrameSafeHandle> task;
+        using (var sqlParametersData = PinnedProtobufData.FromMessage(parametersProto))
+        using (var sqlOptionsData = PinnedProtobufData.FromMessage(optionsProto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.ContextSqlWithOptions(_handle, sql, sqlParametersData.ToBytesData(), sqlOptionsData.ToBytesData(), timeoutMillis, CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start executing SQL query");
+            }
+
+            AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+            
+            task = tcs.Task;
+        }
+        
+        var dataFrameSafeHandle = await task.ConfigureAwait(false);
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+    
+    /// <summary>
+    /// Executes a script of SQL statements separated by semicolons and returns the result of the last statement as a DataFrame.
+    /// </summary>
+    /// <param name="sql">The SQL script to execute.</param>
+    /// <param name="options">Optional options that restrict the allowed statement kinds or select the SQL dialect of every statement.</param>
+    /// <param name="timeout">Maximum duration of the whole script. If null or <see cref="Timeout.InfiniteTimeSpan"/>, the operation does not time out.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the <see cref="DataFrame"/> of the last statement.</returns>
+    /// <exception cref="DataFusionException">
+    /// Thrown when a statement fails. The message names the 1-based index, line and column of the failing statement.
+    /// </exception>
+    /// <exception cref="TimeoutException">Thrown when the operation times out.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <remarks>
+    /// All statements are parsed before the first one runs. Every statement but the last is fully executed and its results are discarded,
+    /// so execution stops at the first failing statement, keeping the effects of the statements before it.
+    /// </remarks>
+    /// <example>
+    /// <code language="csharp">
+    /// var df = await session.SqlScriptAsync("CREATE TABLE t (a INT); INSERT INTO t VALUES (1), (2); SELECT sum(a) FROM t");
+    /// </code>
+    /// </example>
+    public async Task<DataFrame> SqlScriptAsync(string sql, SqlOptions? options = null, TimeSpan? timeout = null, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(sql);
+        var timeoutMillis = NativeTimeout.ToMillis(timeout);
+
+        Task<DataFrameSafeHandle> task;
+        using (var sqlOptionsData = PinnedProtobufData.FromMessage(options?.ToProto()))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
+            var result = NativeMethods.ContextSqlScript(_handle, sql, sqlOptionsData.ToBytesData(), timeoutMillis, CallbackForDataFrameHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start executing SQL script");
+            }
+
+            AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+            task = tcs.Task;
+        }
+
+        var dataFrameSafeHandle = await task.ConfigureAwait(false);
+        return new DataFrame(this, dataFrameSafeHandle);
+    }
+
+    /// <summary>
+    /// Parses and plans a SQL query once for repeated execution with different parameters.
+    /// </summary>
+    /// <param name="sql">The SQL query to prepare, which can contain positional ($1) or named ($name) placeholders.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the <see cref="PreparedStatement"/>.</returns>
+    /// <exception cref="DataFusionException">Thrown when the query cannot be planned.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    /// <remarks>Statements with side effects, such as DDL, run on every execution rather than when prepared.</remarks>
+    /// <example>
+    /// <code language="csharp">
+    /// using var statement = await session.PrepareAsync("SELECT * FROM my_table WHERE id = $1");
+    /// using var df = await statement.ExecuteAsync([new SqlPositionalParameter(123)]);
+    /// </code>
+    /// </example>
+    public Task<PreparedStatement> PrepareAsync(string sql, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(sql);
+
+        return PrepareAsync(sql, (Proto.SqlOptions?) null, cancellationToken);
+    }
+
+    /// <summary>
+    /// Parses and plans a SQL query restricted by <paramref name="options"/> once for repeated execution with different parameters.
+    /// </summary>
+    /// <param name="sql">The SQL query to prepare, which can contain positional ($1) or named ($name) placeholders.</param>
+    /// <param name="options">Options that restrict the allowed statement kinds or select the SQL dialect of the query.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>A task containing the <see cref="PreparedStatement"/>.</returns>
+    /// <exception cref="DataFusionException">
+    /// Thrown when the query cannot be planned, with <see cref="DataFusionErrorCode.StatementNotAllowed"/> if the statement is rejected by <paramref name="options"/>.
+    /// </exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task<PreparedStatement> PrepareAsync(string sql, SqlOptions options, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(sql);
+        ArgumentNullException.ThrowIfNull(options);
+
+        return PrepareAsync(sql, options.ToProto(), cancellationToken);
+    }
+
+    private async Task<PreparedStatement> PrepareAsync(string sql, Proto.SqlOptions? optionsProto, CancellationToken cancellationToken)
+    {
+        Task<PreparedStatementSafeHandle> task;
+        using (var sqlOptionsData = PinnedProtobufData.FromMessage(optionsProto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<PreparedStatementSafeHandle>();
+            var result = NativeMethods.ContextPrepare(_handle, sql, sqlOptionsData.ToBytesData(), CallbackForPreparedStatementHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start preparing SQL query");
+            }
+
+            AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+            task = tcs.Task;
+        }
+
+        var statementSafeHandle = await task.ConfigureAwait(false);
+        return new PreparedStatement(this, statementSafeHandle);
+    }
+
+    /// <summary>
+    /// Plans a SQL query without executing it and returns the inferred types of its placeholders.
+    /// </summary>
+    /// <param name="sql">The SQL query to plan, which can contain positional ($1) or named ($name) placeholders.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>
+    /// A task containing the placeholders of the query. Positional placeholders come first in numeric order, then named ones by name.
+    /// </returns>
+    /// <exception cref="DataFusionException">Thrown when the query cannot be planned.</exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task<IReadOnlyList<SqlParameterType>> GetParameterTypesAsync(string sql, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(sql);
+
+        return GetParameterTypesAsync(sql, (Proto.SqlOptions?) null, cancellationToken);
+    }
+
+    /// <summary>
+    /// Plans a SQL query restricted by <paramref name="options"/> without executing it and returns the inferred types of its placeholders.
+    /// </summary>
+    /// <param name="sql">The SQL query to plan, which can contain positional ($1) or named ($name) placeholders.</param>
+    /// <param name="options">Options that restrict the allowed statement kinds or select the SQL dialect of the query.</param>
+    /// <param name="cancellationToken">Token that cancels the operation and aborts its native execution.</param>
+    /// <returns>
+    /// A task containing the placeholders of the query. Positional placeholders come first in numeric order, then named ones by name.
+    /// </returns>
+    /// <exception cref="DataFusionException">
+    /// Thrown when the query cannot be planned, with <see cref="DataFusionErrorCode.StatementNotAllowed"/> if the statement is rejected by <paramref name="options"/>.
+    /// </exception>
+    /// <exception cref="OperationCanceledException">Thrown when the operation is cancelled.</exception>
+    public Task<IReadOnlyList<SqlParameterType>> GetParameterTypesAsync(string sql, SqlOptions options, CancellationToken cancellationToken = default)
+    {
+        ArgumentNullException.ThrowIfNull(sql);
+        ArgumentNullException.ThrowIfNull(options);
+
+        return GetParameterTypesAsync(sql, options.ToProto(), cancellationToken);
+    }
+
+    private async Task<IReadOnlyList<SqlParameterType>> GetParameterTypesAsync(string sql, Proto.SqlOptions? optionsProto, CancellationToken cancellationToken)
+    {
+        Task<byte[]> task;
+        using (var sqlOptionsData = PinnedProtobufData.FromMessage(optionsProto))
+        {
+            var (id, tcs) = AsyncOperations.Instance.Create<byte[]>();
+            var result = NativeMethods.ContextSqlParameterTypes(_handle, sql, sqlOptionsData.ToBytesData(), GenericCallbacks.CallbackForBytesHandle, id);
+            if (result != DataFusionErrorCode.Ok)
+            {
+                AsyncOperations.Instance.Abort(id);
+                throw new DataFusionException(result, "Failed to start inferring SQL parameter types");
+            }
+
+            AsyncOperations.Instance.RegisterCancellation(id, cancellationToken);
+
+            task = tcs.Task;
+        }
+
+        var bytes = await task.ConfigureAwait(false);
+        return SqlParameterType.FromProtoBytes(bytes);
+    }
+
+    /// <summary>
+    /// Creates an empty in-memory catalog in this session.
+    /// </summary>
+    /// <param name="catalogName">The name of the catalog to create.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when a catalog with the same name already exists.</exception>
+    public Task CreateCatalogAsync(string catalogName)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(catalogName);
+
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextCreateCatalog(_handle, catalogName, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start creating catalog");
+        }
+
         return tcs.Task;
     }
-    
+
     /// <summary>
-    /// Registers a JSON file as a table in this session.
+    /// Drops a catalog from this session.
     /// </summary>
-    /// <param name="tableName">The name to use for the table.</param>
-    /// <param name="filePath">The path to the JSON file.</param>
-    /// <param name="options">Optional JSON read options to customize parsing behavior.</param>
+    /// <param name="catalogName">The name of the catalog to drop.</param>
+    /// <param name="cascade">Whether to drop the catalog together with its schemas; otherwise only an empty catalog can be dropped.</param>
     /// <returns>A task representing the asynchronous operation.</returns>
-    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
-    public Task RegisterJsonAsync(string tableName, string filePath, JsonReadOptions? options = null)
+    /// <exception cref="DataFusionException">Thrown when the catalog does not exist, is the default catalog or is not empty.</exception>
+    public Task DropCatalogAsync(string catalogName, bool cascade = false)
     {
-        using var optionsData = PinnedProtobufData.FromMessage(options?.ToProto());
+        ArgumentException.ThrowIfNullOrEmpty(catalogName);
 
         var (id, tcs) = AsyncOperations.Instance.Create();
-        var result = NativeMethods.ContextRegisterJson(_handle, tableName, filePath, optionsData.ToBytesData(), GenericCallbacks.CallbackForVoidHandle, id);
+        var result = NativeMethods.ContextDropCatalog(_handle, catalogName, cascade, GenericCallbacks.CallbackForVoidHandle, id);
         if (result != DataFusionErrorCode.Ok)
         {
             AsyncOperations.Instance.Abort(id);
-            throw new DataFusionException(result, "Failed to start registering JSON file");
+            throw new DataFusionException(result, "Failed to start dropping catalog");
         }
+
         return tcs.Task;
     }
-    
+
     /// <summary>
-    /// Registers a Parquet file as a table in this session.
+    /// Creates an empty in-memory schema in a catalog of this session.
     /// </summary>
-    /// <param name="tableName">The name to use for the table.</param>
-    /// <param name="filePath">The path to the Parquet file.</param>
+    /// <param name="catalogName">The name of the catalog that will contain the schema.</param>
+    /// <param name="schemaName">The name of the schema to create.</param>
     /// <returns>A task representing the asynchronous operation.</returns>
-    /// <exception cref="DataFusionException">Thrown when table registration fails.</exception>
-    public Task RegisterParquetAsync(string tableName, string filePath)
+    /// <exception cref="DataFusionException">Thrown when the catalog does not exist or already contains the schema.</exception>
+    public Task CreateSchemaAsync(string catalogName, string schemaName)
     {
+        ArgumentException.ThrowIfNullOrEmpty(catalogName);
+        ArgumentException.ThrowIfNullOrEmpty(schemaName);
+
         var (id, tcs) = AsyncOperations.Instance.Create();
-        var result = NativeMethods.ContextRegisterParquet(_handle, tableName, filePath, GenericCallbacks.CallbackForVoidHandle, id);
+        var result = NativeMethods.ContextCreateSchema(_handle, catalogName, schemaName, GenericCallbacks.CallbackForVoidHandle, id);
         if (result != DataFusionErrorCode.Ok)
         {
             AsyncOperations.Instance.Abort(id);
-            throw new DataFusionException(result, "Failed to start registering Parquet file");
+            throw new DataFusionException(result, "Failed to start creating schema");
         }
+
         return tcs.Task;
     }
-    
+
     /// <summary>
-    /// Deregisters a table from this session.
+    /// Drops a schema from a catalog of this session.
     /// </summary>
-    /// <param name="tableName">The name of the table to deregister.</param>
+    /// <param name="catalogName">The name of the catalog containing the schema.</param>
+    /// <param name="schemaName">The name of the schema to drop.</param>
+    /// <param name="cascade">Whether to drop the schema together with its tables; otherwise only an empty schema can be dropped.</param>
     /// <returns>A task representing the asynchronous operation.</returns>
-    /// <exception cref="DataFusionException">Thrown when table deregistration fails.</exception>
-    public Task DeregisterTableAsync(string tableName)
+    /// <exception cref="DataFusionException">Thrown when the catalog or schema does not exist or the schema is not empty.</exception>
+    public Task DropSchemaAsync(string catalogName, string schemaName, bool cascade = false)
     {
+        ArgumentException.ThrowIfNullOrEmpty(catalogName);
+        ArgumentException.ThrowIfNullOrEmpty(schemaName);
+
         var (id, tcs) = AsyncOperations.Instance.Create();
-        var result = NativeMethods.ContextDeregisterTable(_handle, tableName, GenericCallbacks.CallbackForVoidHandle, id);
+        var result = NativeMethods.ContextDropSchema(_handle, catalogName, schemaName, cascade, GenericCallbacks.CallbackForVoidHandle, id);
         if (result != DataFusionErrorCode.Ok)
         {
             AsyncOperations.Instance.Abort(id);
-            throw new DataFusionException(result, "Failed to start deregistering table");
+            throw new DataFusionException(result, "Failed to start dropping schema");
         }
+
         return tcs.Task;
     }
 
     /// <summary>
-    /// Executes a SQL query and returns the result as a DataFrame.
+    /// Returns the names of the catalogs of this session, ordered by name.
     /// </summary>
-    /// <param name="sql">The SQL query to execute.</param>
-    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
-    /// <exception cref="DataFusionException">Thrown when query execution fails.</exception>
-    /// <example>
-    /// <code language="csharp">
-    /// var df = await session.SqlAsync("SELECT * FROM my_table");
-    /// </code>
-    /// </example>
-    public async Task<DataFrame> SqlAsync(string sql)
+    /// <returns>A task containing the catalog names.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<IReadOnlyList<string>> ListCatalogsAsync()
     {
-        ArgumentNullException.ThrowIfNull(sql);
-        
-        var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
-        var result = NativeMethods.ContextSql(_handle, sql, BytesData.Empty, CallbackForSqlAsyncHandle, id);
+        var (id, tcs) = AsyncOperations.Instance.Create<byte[]>();
+        var result = NativeMethods.ContextListCatalogs(_handle, GenericCallbacks.CallbackForBytesHandle, id);
         if (result != DataFusionErrorCode.Ok)
         {
             AsyncOperations.Instance.Abort(id);
-            throw new DataFusionException(result, "Failed to start executing SQL query");
+            throw new DataFusionException(result, "Failed to start listing catalogs");
         }
 
-        var dataFrameSafeHandle = await tcs.Task.ConfigureAwait(false);
-        return new DataFrame(this, dataFrameSafeHandle);
+        var bytes = await tcs.Task.ConfigureAwait(false);
+        return Proto.CatalogList.Parser.ParseFrom(bytes).Catalogs.ToList().AsReadOnly();
     }
-    
+
     /// <summary>
-    /// Executes a SQL query with named parameters and returns the result as a DataFrame.
+    /// Returns the names of the schemas of a catalog, ordered by name.
     /// </summary>
-    /// <param name="sql">The SQL query to execute, which can contain named parameter placeholders (e.g., $paramName).</param>
-    /// <param name="parameters">A named parameters to bind to the query.</param>
-    /// <returns>A task containing the resulting <see cref="DataFrame"/>.</returns>
-    /// <exception cref="DataFusionException">Thrown when query execution fails.</exception>
-    /// <example>
-    /// <code language="csharp">
-    /// var df = await session.SqlAsync("SELECT * FROM my_table WHERE id = $id", [("id", 123)]);
-    /// </code>
-    /// </example>
-    public async Task<DataFrame> SqlAsync(string sql, IEnumerable<SqlNamedParameter> parameters)
+    /// <param name="catalogName">The name of the catalog.</param>
+    /// <returns>A task containing the schema names.</returns>
+    /// <exception cref="DataFusionException">Thrown when the catalog does not exist.</exception>
+    public async Task<IReadOnlyList<string>> ListSchemasAsync(string catalogName)
     {
-        ArgumentNullException.ThrowIfNull(sql);
-        ArgumentNullException.ThrowIfNull(parameters);
-        
-        var parametersProto = new Proto.SqlParameters();
-        foreach (var param in parameters)
-            parametersProto.Values.Add(param.Name, param.ProtoValue);
+        ArgumentException.ThrowIfNullOrEmpty(catalogName);
 
-        Task<DataFrameSafeHandle> task;
-        using (var sqlParametersData = PinnedProtobufData.FromMessage(parametersProto))
+        var (id, tcs) = AsyncOperations.Instance.Create<byte[]>();
+        var result = NativeMethods.ContextListSchemas(_handle, catalogName, GenericCallbacks.CallbackForBytesHandle, id);
+        if (result != DataFusionErrorCode.Ok)
         {
-            var (id, tcs) = AsyncOperations.Instance.Create<DataFrameSafeHandle>();
-            var result = NativeMethods.ContextSql(_handle, sql, sqlParametersData.ToBytesData(), CallbackForSqlAsyncHandle, id);
-            if (result != DataFusionErrorCode.Ok)
-            {
-                AsyncOperations.Instance.Abort(id);
-                throw new DataFusionException(result, "Failed to start executing SQL query");
-            }
-            
-            task = tcs.Task;
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start listing schemas");
         }
-        
-        var dataFrameSafeHandle = await task.ConfigureAwait(false);
-        return new DataFrame(this, dataFrameSafeHandle);
+
+        var bytes = await tcs.Task.ConfigureAwait(false);
+        return Proto.SchemaList.Parser.ParseFrom(bytes).Schemas.ToList().AsReadOnly();
     }
-    
+
+    /// <summary>
+    /// Returns the names of the tables of a schema, ordered by name.
+    /// </summary>
+    /// <param name="catalogName">The name of the catalog containing the schema.</param>
+    /// <param name="schemaName">The name of the schema.</param>
+    /// <returns>A task containing the table names.</returns>
+    /// <exception cref="DataFusionException">Thrown when the catalog or schema does not exist.</exception>
+    public async Task<IReadOnlyList<string>> ListTablesAsync(string catalogName, string schemaName)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(catalogName);
+        ArgumentException.ThrowIfNullOrEmpty(schemaName);
+
+        var (id, tcs) = AsyncOperations.Instance.Create<byte[]>();
+        var result = NativeMethods.ContextListTables(_handle, catalogName, schemaName, GenericCallbacks.CallbackForBytesHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start listing tables");
+        }
+
+        var bytes = await tcs.Task.ConfigureAwait(false);
+        return Proto.TableList.Parser.ParseFrom(bytes).Tables.ToList().AsReadOnly();
+    }
+
+    /// <summary>
+    /// Checks whether a table is registered in this session.
+    /// </summary>
+    /// <param name="tableReference">The table name, optionally qualified as <c>schema.table</c> or <c>catalog.schema.table</c>.</param>
+    /// <returns>A task containing <c>true</c> when the table exists.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public Task<bool> TableExistsAsync(string tableReference)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(tableReference);
+
+        var (id, tcs) = AsyncOperations.Instance.Create<bool>();
+        var result = NativeMethods.ContextTableExists(_handle, tableReference, GenericCallbacks.CallbackForBoolHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start checking table existence");
+        }
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Sets the SQL dialect used to parse queries of this session, equivalent to setting <c>datafusion.sql_parser.dialect</c>.
+    /// </summary>
+    /// <param name="dialect">Name of the dialect, e.g. "generic", "postgresql", "mysql", "snowflake" or "bigquery".</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when the dialect is unknown.</exception>
+    /// <remarks>A dialect set for a single query through <see cref="SqlOptions.Dialect"/> takes precedence.</remarks>
+    public Task SetDialectAsync(string dialect)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(dialect);
+
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextSetDialect(_handle, dialect, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start setting SQL dialect");
+        }
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Sets a configuration option of this session. The new value applies to queries planned afterwards.
+    /// </summary>
+    /// <param name="key">Full option key, e.g. <c>datafusion.execution.batch_size</c>.</param>
+    /// <param name="value">The value to set.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when the option is unknown or the value is invalid.</exception>
+    public Task SetOptionAsync(string key, string value)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(key);
+        ArgumentNullException.ThrowIfNull(value);
+
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextSetOption(_handle, key, value, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start setting option");
+        }
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Returns the effective configuration of this session, ordered by key.
+    /// </summary>
+    /// <returns>A task containing the configuration options with their current values.</returns>
+    /// <exception cref="DataFusionException">Thrown when the operation fails.</exception>
+    public async Task<IReadOnlyList<ConfigEntry>> GetOptionsAsync()
+    {
+        var (id, tcs) = AsyncOperations.Instance.Create<byte[]>();
+        var result = NativeMethods.ContextGetOptions(_handle, GenericCallbacks.CallbackForBytesHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start getting options");
+        }
+
+        var bytes = await tcs.Task.ConfigureAwait(false);
+        return Proto.ConfigEntryList.Parser.ParseFrom(bytes).Entries
+            .Select(e => new ConfigEntry(e.Key, e.HasValue ? e.Value : null, e.Description))
+            .ToList()
+            .AsReadOnly();
+    }
+
+    /// <summary>
+    /// Registers a scalar user-defined function implemented by a managed delegate.
+    /// </summary>
+    /// <param name="name">The name of the function used in SQL.</param>
+    /// <param name="argumentTypes">The exact types of the arguments accepted by the function.</param>
+    /// <param name="returnType">The type of the values returned by the function.</param>
+    /// <param name="function">
+    /// Maps a batch of arguments, with one column per argument named <c>arg0</c>, <c>arg1</c>, ..., to an array of
+    /// <paramref name="returnType"/> with one value per row of the batch.
+    /// </param>
+    /// <param name="volatility">How the output of the function changes with the same input.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when function registration fails.</exception>
+    /// <remarks>
+    /// The function may be called concurrently from native worker threads. The argument batch is only valid during the call,
+    /// so the returned array must not share memory with it. An exception thrown by the function fails the query with its message.
+    /// </remarks>
+    public Task RegisterScalarUdfAsync(string name, IReadOnlyList<IArrowType> argumentTypes, IArrowType returnType, Func<RecordBatch, IArrowArray> function, Volatility volatility = Volatility.Immutable)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        ArgumentNullException.ThrowIfNull(argumentTypes);
+        ArgumentNullException.ThrowIfNull(returnType);
+        ArgumentNullException.ThrowIfNull(function);
+
+        var signature = new Proto.ScalarUdfSignature { ReturnType = returnType.ToProto(), Volatility = volatility.ToProto() };
+        signature.ArgTypes.Add(argumentTypes.Select(t => t.ToProto()));
+        using var signatureData = PinnedProtobufData.FromMessage(signature);
+
+        var functionUserData = FunctionCallbacks.Allocate(function);
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextRegisterScalarUdf(_handle, name, signatureData.ToBytesData(), FunctionCallbacks.ArrayFunctionHandle, FunctionCallbacks.ReleaseHandle, functionUserData, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            FunctionCallbacks.Free(functionUserData);
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start registering scalar UDF");
+        }
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Registers an aggregate user-defined function whose accumulators are implemented in managed code.
+    /// </summary>
+    /// <param name="name">The name of the function used in SQL.</param>
+    /// <param name="argumentTypes">The exact types of the arguments accepted by the function. May be empty.</param>
+    /// <param name="returnType">The type of the aggregated value.</param>
+    /// <param name="stateTypes">The types of the intermediate state values returned by <see cref="IAccumulator.GetState"/>.</param>
+    /// <param name="createAccumulator">Creates a new accumulator; called for every group and partition of a query.</param>
+    /// <param name="volatility">How the output of the function changes with the same input.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when function registration fails.</exception>
+    /// <remarks>
+    /// Accumulators are called from native worker threads. An exception thrown by an accumulator fails the query with its message.
+    /// </remarks>
+    public Task RegisterAggregateUdfAsync(string name, IReadOnlyList<IArrowType> argumentTypes, IArrowType returnType, IReadOnlyList<IArrowType> stateTypes, Func<IAccumulator> createAccumulator, Volatility volatility = Volatility.Immutable)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        ArgumentNullException.ThrowIfNull(argumentTypes);
+        ArgumentNullException.ThrowIfNull(returnType);
+        ArgumentNullException.ThrowIfNull(stateTypes);
+        ArgumentNullException.ThrowIfNull(createAccumulator);
+
+        var signature = new Proto.AggregateUdfSignature { ReturnType = returnType.ToProto(), Volatility = volatility.ToProto() };
+        signature.ArgTypes.Add(argumentTypes.Select(t => t.ToProto()));
+        signature.StateTypes.Add(stateTypes.Select(t => t.ToProto()));
+        using var signatureData = PinnedProtobufData.FromMessage(signature);
+
+        var functionUserData = FunctionCallbacks.Allocate(createAccumulator);
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextRegisterAggregateUdf(_handle, name, signatureData.ToBytesData(), FunctionCallbacks.AccumulatorCallbacks, FunctionCallbacks.ReleaseHandle, functionUserData, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            FunctionCallbacks.Free(functionUserData);
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start registering aggregate UDF");
+        }
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Registers a window user-defined function implemented by a managed delegate.
+    /// </summary>
+    /// <param name="name">The name of the function used in SQL.</param>
+    /// <param name="argumentTypes">The exact types of the arguments accepted by the function.</param>
+    /// <param name="returnType">The type of the values returned by the function.</param>
+    /// <param name="evaluate">
+    /// Maps all rows of a window partition, in the order of the window, with one column per argument named <c>arg0</c>, <c>arg1</c>, ...,
+    /// to an array of <paramref name="returnType"/> with one value per row. Window frames are not passed to the function.
+    /// </param>
+    /// <param name="volatility">How the output of the function changes with the same input.</param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when function registration fails.</exception>
+    /// <remarks>
+    /// The function may be called concurrently from native worker threads. The argument batch is only valid during the call,
+    /// so the returned array must not share memory with it. An exception thrown by the function fails the query with its message.
+    /// </remarks>
+    public Task RegisterWindowUdfAsync(string name, IReadOnlyList<IArrowType> argumentTypes, IArrowType returnType, Func<RecordBatch, IArrowArray> evaluate, Volatility volatility = Volatility.Immutable)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        ArgumentNullException.ThrowIfNull(argumentTypes);
+        ArgumentNullException.ThrowIfNull(returnType);
+        ArgumentNullException.ThrowIfNull(evaluate);
+
+        var signature = new Proto.WindowUdfSignature { ReturnType = returnType.ToProto(), Volatility = volatility.ToProto() };
+        signature.ArgTypes.Add(argumentTypes.Select(t => t.ToProto()));
+        using var signatureData = PinnedProtobufData.FromMessage(signature);
+
+        var functionUserData = FunctionCallbacks.Allocate(evaluate);
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextRegisterWindowUdf(_handle, name, signatureData.ToBytesData(), FunctionCallbacks.ArrayFunctionHandle, FunctionCallbacks.ReleaseHandle, functionUserData, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            FunctionCallbacks.Free(functionUserData);
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start registering window UDF");
+        }
+
+        return tcs.Task;
+    }
+
+    /// <summary>
+    /// Registers a table function implemented by a managed delegate, usable in SQL as <c>SELECT * FROM name('x', 3)</c>.
+    /// </summary>
+    /// <param name="name">The name of the function used in SQL.</param>
+    /// <param name="function">
+    /// Produces the rows of a call from its arguments, passed as a single-row batch with one column per argument
+    /// named <c>arg0</c>, <c>arg1</c>, .... Ownership of the returned stream is transferred to the query.
+    /// </param>
+    /// <returns>A task representing the asynchronous operation.</returns>
+    /// <exception cref="DataFusionException">Thrown when function registration fails.</exception>
+    /// <remarks>
+    /// Arguments must be literals. The function is called once when a query referencing it is planned and once more for every
+    /// execution of the same DataFrame after the first; every call must return a stream with the same schema.
+    /// The argument batch is only valid during the call and the stream is read from native worker threads.
+    /// </remarks>
+    public Task RegisterTableFunctionAsync(string name, Func<RecordBatch, IArrowArrayStream> function)
+    {
+        ArgumentException.ThrowIfNullOrEmpty(name);
+        ArgumentNullException.ThrowIfNull(function);
+
+        var functionUserData = FunctionCallbacks.Allocate(function);
+        var (id, tcs) = AsyncOperations.Instance.Create();
+        var result = NativeMethods.ContextRegisterTableFunction(_handle, name, FunctionCallbacks.TableFunctionHandle, FunctionCallbacks.ReleaseHandle, functionUserData, GenericCallbacks.CallbackForVoidHandle, id);
+        if (result != DataFusionErrorCode.Ok)
+        {
+            FunctionCallbacks.Free(functionUserData);
+            AsyncOperations.Instance.Abort(id);
+            throw new DataFusionException(result, "Failed to start registering table function");
+        }
+
+        return tcs.Task;
+    }
+
     /// <inheritdoc />
     public void Dispose()
     {
@@ -183,7 +1187,7 @@ public sealed partial class SessionContext : IDisposable
     }
     
     [DataFusionSharpNativeCallback]
-    private static void CallbackForSqlAsync(IntPtr result, IntPtr error, ulong handle)
+    internal static void CallbackForDataFrame(IntPtr result, IntPtr error, ulong handle)
     {
         if (error != IntPtr.Zero)
         {
@@ -198,6 +1202,23 @@ public sealed partial class SessionContext : IDisposable
 #pragma warning restore CA2000
         AsyncOperations.Instance.CompleteWithResult(handle, dataFrameSafeHandle);
     }
+    
+    [DataFusionSharpNativeCallback]
+    private static void CallbackForPreparedStatement(IntPtr result, IntPtr error, ulong handle)
+    {
+        if (error != IntPtr.Zero)
+        {
+            var ex = ErrorInfoData.FromIntPtr(error).ToException();
+            AsyncOperations.Instance.CompleteWithError<PreparedStatementSafeHandle>(handle, ex);
+            return;
+        }
+
+        var statementHandle = Marshal.ReadIntPtr(result);
+#pragma warning disable CA2000
+        var statementSafeHandle = new PreparedStatementSafeHandle(statementHandle);
+#pragma warning restore CA2000
+        AsyncOperations.Instance.CompleteWithResult(handle, statementSafeHandle);
+    }
 }
 
 /// <summary>
@@ -253,3 +1274,30 @@ public readonly record struct SqlNamedParameter
     /// <exception cref="ArgumentException">Thrown when the parameter name is invalid or when the value type is unsupported.</exception>
     public static SqlNamedParameter ToSqlNamedParameter((string Name, object? Value) tuple) => new(tuple.Name, tuple.Value);
 }
+
+/// <summary>
+/// Represents a positional parameter to be passed to a SQL query, bound to the placeholder of its position ($1, $2, ...).
+/// </summary>
+public readonly record struct SqlPositionalParameter
+{
+    /// <summary>
+    /// Gets the value of the parameter.
+    /// </summary>
+    public object? Value { get; }
+    
+    /// <summary>
+    /// Gets the value of the parameter converted to a protobuf ScalarValue.
+    /// </summary>
+    internal Proto.ScalarValue ProtoValue { get; }
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="SqlPositionalParameter"/> struct with the specified value.
+    /// </summary>
+    /// <param name="value">The value of the parameter. Must be a primitive type or byte array.</param>
+    /// <exception cref="ArgumentException">Thrown when the value type is unsupported.</exception>
+    public SqlPositionalParameter(object? value)
+    {
+        Value = value;
+        ProtoValue = value.ToProtoScalarValue();
+    }
+}
@@ -0,0 +1,169 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// Configuration of a new <see cref="SessionContext"/>.
+/// </summary>
+public sealed class SessionContextOptions
+{
+    /// <summary>
+    /// Number of partitions for query execution. Zero uses the number of available CPUs. If null, DataFusion uses its default.
+    /// </summary>
+    public ulong? TargetPartitions { get; set; }
+
+    /// <summary>
+    /// Default batch size while creating new batches. If null, DataFusion uses its default (8192).
+    /// </summary>
+    public ulong? BatchSize { get; set; }
+
+    /// <summary>
+    /// Whether <c>information_schema</c> virtual tables are available. If null, DataFusion uses its default (false).
+    /// </summary>
+    public bool? InformationSchema { get; set; }
+
+    /// <summary>
+    /// Default catalog name. If null, DataFusion uses its default ("datafusion").
+    /// </summary>
+    public string? DefaultCatalog { get; set; }
+
+    /// <summary>
+    /// Default schema name. If null, DataFusion uses its default ("public").
+    /// </summary>
+    public string? DefaultSchema { get; set; }
+
+    /// <summary>
+    /// Whether unquoted identifiers are normalized to lowercase, i.e. treated case-insensitively. If null, DataFusion uses its default (true).
+    /// </summary>
+    public bool? EnableIdentNormalization { get; set; }
+
+    /// <summary>
+    /// SQL dialect used to parse queries, e.g. "postgresql", "mysql", "snowflake" or "bigquery". If null, DataFusion uses its default ("generic").
+    /// </summary>
+    public string? Dialect { get; set; }
+
+    /// <summary>
+    /// Any DataFusion option by its full key, e.g. <c>datafusion.execution.coalesce_batches</c>.
+    /// Typed properties of this class take precedence over entries with the same meaning.
+    /// </summary>
+    public IReadOnlyDictionary<string, string>? Options { get; set; }
+
+    /// <summary>
+    /// Memory pool used to limit memory of query execution. Requires <see cref="MemoryLimit"/> unless unbounded.
+    /// </summary>
+    public MemoryPoolType MemoryPool { get; set; } = MemoryPoolType.Unbounded;
+
+    /// <summary>
+    /// Memory limit in bytes for the <see cref="MemoryPool"/>. Queries exceeding it fail with
+    /// <see cref="DataFusionErrorCode.ResourcesExhausted"/> unless they can spill to disk.
+    /// </summary>
+    public ulong? MemoryLimit { get; set; }
+
+    /// <summary>
+    /// Directories for spill files. If null or empty, the OS temporary directory is used.
+    /// </summary>
+    public IReadOnlyList<string>? SpillDirectories { get; set; }
+
+    /// <summary>
+    /// Whether spilling to disk is disabled. If null, spilling is enabled.
+    /// </summary>
+    public bool? DisableSpilling { get; set; }
+
+    /// <summary>
+    /// Maximum total size of spill files in bytes. If null, DataFusion uses its default (100 GiB).
+    /// </summary>
+    public ulong? MaxTempDirectorySize { get; set; }
+
+    /// <summary>
+    /// Whether file statistics of listing tables are cached. If null, the cache is disabled.
+    /// </summary>
+    public bool? EnableFileStatisticsCache { get; set; }
+
+    /// <summary>
+    /// Limit of the list files cache in bytes; zero disables the cache. If null, DataFusion uses its default (1 MiB).
+    /// </summary>
+    public ulong? ListFilesCacheLimit { get; set; }
+
+    /// <summary>
+    /// Time-to-live of list files cache entries. If null, entries do not expire.
+    /// </summary>
+    public TimeSpan? ListFilesCacheTtl { get; set; }
+
+    /// <summary>
+    /// Limit of the file-embedded metadata cache (e.g. Parquet footers) in bytes. If null, DataFusion uses its default.
+    /// </summary>
+    public ulong? MetadataCacheLimit { get; set; }
+
+    internal Proto.SessionConfig ToProto()
+    {
+        var proto = new Proto.SessionConfig();
+
+        if (TargetPartitions.HasValue)
+            proto.TargetPartitions = TargetPartitions.Value;
+
+        if (BatchSize.HasValue)
+            proto.BatchSize = BatchSize.Value;
+
+        if (InformationSchema.HasValue)
+            proto.InformationSchema = InformationSchema.Value;
+
+        if (DefaultCatalog is not null)
+            proto.DefaultCatalog = DefaultCatalog;
+
+        if (DefaultSchema is not null)
+            proto.DefaultSchema = DefaultSchema;
+
+        if (EnableIdentNormalization.HasValue)
+            proto.EnableIdentNormalization = EnableIdentNormalization.Value;
+
+        if (Dialect is not null)
+            proto.Dialect = Dialect;
+
+        if (Options is not null)
+            proto.Options.Add(Options);
+
+        return proto;
+    }
+
+    internal Proto.RuntimeEnvConfig ToRuntimeEnvProto()
+    {
+        var proto = new Proto.RuntimeEnvConfig
+        {
+            MemoryPoolType = MemoryPool switch
+            {
+                MemoryPoolType.Unbounded => Proto.MemoryPoolType.Unbounded,
+                MemoryPoolType.Greedy => Proto.MemoryPoolType.Greedy,
+                MemoryPoolType.Fair => Proto.MemoryPoolType.Fair,
+                _ => throw new ArgumentOutOfRangeException(nameof(MemoryPool), MemoryPool, "Unknown memory pool type")
+            }
+        };
+
+        if (MemoryLimit.HasValue)
+            proto.MemoryLimit = MemoryLimit.Value;
+
+        if (SpillDirectories is { Count: > 0 })
+            proto.SpillDirectories.AddRange(SpillDirectories);
+
+        if (DisableSpilling.HasValue)
+            proto.DisableSpilling = DisableSpilling.Value;
+
+        if (MaxTempDirectorySize.HasValue)
+            proto.MaxTempDirectorySize = MaxTempDirectorySize.Value;
+
+        var cache = new Proto.CacheManagerConfig();
+
+        if (EnableFileStatisticsCache.HasValue)
+            cache.EnableFileStatisticsCache = EnableFileStatisticsCache.Value;
+
+        if (ListFilesCacheLimit.HasValue)
+            cache.ListFilesCacheLimit = ListFilesCacheLimit.Value;
+
+        if (ListFilesCacheTtl.HasValue)
+            cache.ListFilesCacheTtlMillis = (ulong) ListFilesCacheTtl.Value.TotalMilliseconds;
+
+        if (MetadataCacheLimit.HasValue)
+            cache.MetadataCacheLimit = MetadataCacheLimit.Value;
+
+        proto.CacheManager = cache;
+
+        return proto;
+    }
+}
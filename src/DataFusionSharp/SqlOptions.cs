@@ -0,0 +1,52 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// Restrictions and parsing options of a single SQL query. Statements of a disallowed kind fail with
+/// <see cref="DataFusionErrorCode.StatementNotAllowed"/> without being executed.
+/// </summary>
+public sealed class SqlOptions
+{
+    /// <summary>
+    /// Whether DDL such as <c>CREATE EXTERNAL TABLE</c> and <c>DROP TABLE</c> is allowed. If null, DDL is allowed.
+    /// </summary>
+    public bool? AllowDdl { get; set; }
+
+    /// <summary>
+    /// Whether DML such as <c>INSERT INTO</c> and <c>COPY</c> is allowed. If null, DML is allowed.
+    /// </summary>
+    public bool? AllowDml { get; set; }
+
+    /// <summary>
+    /// Whether statements such as <c>SET</c>, <c>BEGIN</c> and <c>PREPARE</c> are allowed. If null, they are allowed.
+    /// </summary>
+    public bool? AllowStatements { get; set; }
+
+    /// <summary>
+    /// SQL dialect used to parse the query, e.g. "postgresql" or "mysql". If null, the dialect of the session is used.
+    /// </summary>
+    public string? Dialect { get; set; }
+
+    /// <summary>
+    /// Options that allow only read-only queries: DDL, DML and statements such as <c>SET</c> are rejected.
+    /// </summary>
+    public static SqlOptions ReadOnly => new() { AllowDdl = false, AllowDml = false, AllowStatements = false };
+
+    internal Proto.SqlOptions ToProto()
+    {
+        var proto = new Proto.SqlOptions();
+
+        if (AllowDdl.HasValue)
+            proto.AllowDdl = AllowDdl.Value;
+
+        if (AllowDml.HasValue)
+            proto.AllowDml = AllowDml.Value;
+
+        if (AllowStatements.HasValue)
+            proto.AllowStatements = AllowStatements.Value;
+
+        if (Dialect is not null)
+            proto.Dialect = Dialect;
+
+        return proto;
+    }
+}
@@ -0,0 +1,17 @@
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp;
+
+/// <summary>
+/// A placeholder of a planned SQL query with its inferred type.
+/// </summary>
+/// <param name="Id">Placeholder id including the <c>$</c> prefix, e.g. <c>$1</c> or <c>$name</c>.</param>
+/// <param name="DataType">Inferred type, or null if it cannot be inferred from the query.</param>
+public sealed record SqlParameterType(string Id, IArrowType? DataType)
+{
+    internal static IReadOnlyList<SqlParameterType> FromProtoBytes(byte[] bytes) =>
+        Proto.SqlParameterTypes.Parser.ParseFrom(bytes).Parameters
+            .Select(p => new SqlParameterType(p.Id, p.DataType?.ToArrow([])))
+            .ToList()
+            .AsReadOnly();
+}
@@ -0,0 +1,25 @@
+namespace DataFusionSharp;
+
+/// <summary>
+/// Describes how the output of a user-defined function changes with the same input.
+/// </summary>
+public enum Volatility
+{
+    /// <summary>Always returns the same output for the same input. Calls may be constant-folded during planning.</summary>
+    Immutable,
+    /// <summary>Returns the same output for the same input within a single query.</summary>
+    Stable,
+    /// <summary>May return a different output for each call, even with the same input.</summary>
+    Volatile
+}
+
+internal static class ProtoVolatilityExtensions
+{
+    internal static Proto.Volatility ToProto(this Volatility volatility) => volatility switch
+    {
+        Volatility.Immutable => Proto.Volatility.Immutable,
+        Volatility.Stable => Proto.Volatility.Stable,
+        Volatility.Volatile => Proto.Volatility.Volatile,
+        _ => throw new ArgumentOutOfRangeException(nameof(volatility), volatility, "Invalid Volatility value")
+    };
+}
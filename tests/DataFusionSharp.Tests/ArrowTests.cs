@@ -0,0 +1,77 @@
+using DataFusionSharp.Formats.Arrow;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class ArrowTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+    private readonly TempDirectory _tempDirectory = TempDirectory.Create();
+
+    public ArrowTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task ReadArrowAsync_ReturnsData()
+    {
+        // Arrange
+        var path = await WriteCustomersArrowFileAsync();
+
+        // Act
+        using var df = await _context.ReadArrowAsync(path);
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(10UL, count);
+    }
+
+    [Fact]
+    public async Task RegisterArrowAsync_RegistersQueryableTable()
+    {
+        // Arrange
+        var path = await WriteCustomersArrowFileAsync();
+
+        // Act
+        await _context.RegisterArrowAsync("customers_arrow", path);
+
+        // Assert
+        using var df = await _context.SqlAsync("SELECT count(*) AS cnt FROM customers_arrow");
+        using var collected = await df.CollectAsync();
+        Assert.Equal(10L, collected.Batches[0].Column("cnt").AsInt64().First());
+    }
+
+    [Fact]
+    public async Task ReadArrowAsync_WithFileExtensionAndWrongExtension_ReturnsEmptyData()
+    {
+        // Arrange
+        var path = await WriteCustomersArrowFileAsync();
+
+        // Act
+        using var df = await _context.ReadArrowAsync(_tempDirectory.Path, new ArrowReadOptions { FileExtension = ".ipc" });
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.True(File.Exists(path));
+        Assert.Equal(0UL, count);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+        _tempDirectory.Dispose();
+    }
+
+    private async Task<string> WriteCustomersArrowFileAsync()
+    {
+        await _context.RegisterCsvAsync("customers", DataSet.CustomersCsvPath);
+
+        var path = Path.Combine(_tempDirectory.Path, "customers.arrow");
+        using var df = await _context.SqlAsync($"COPY customers TO '{path}' STORED AS ARROW");
+        using var collected = await df.CollectAsync();
+        return path;
+    }
+}
@@ -32,6 +32,11 @@ public sealed class CsvTests : FileFormatTests
         return Context.RegisterCsvAsync(tableName, path);
     }
 
+    protected override Task<DataFrame> ReadCustomersAsync()
+    {
+        return Context.ReadCsvAsync(DataSet.CustomersCsvPath);
+    }
+
     protected override Task WriteTableAsync(DataFrame dataFrame, string path)
     {
         return dataFrame.WriteCsvAsync(path);
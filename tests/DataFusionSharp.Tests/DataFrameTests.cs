@@ -209,6 +209,134 @@ public sealed class DataFrameTests : IDisposable
         }
     }
 
+    [Fact]
+    public async Task ExecuteStreamAsync_WhenBatchTimesOut_ThrowsTimeout()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT count(*) FROM (SELECT * FROM generate_series(1, 100000000) ORDER BY value DESC)");
+        using var stream = await df.ExecuteStreamAsync();
+        stream.BatchTimeout = TimeSpan.FromMilliseconds(100);
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<TimeoutException>(async () =>
+        {
+            await foreach (var _ in stream)
+            {
+            }
+        });
+        var inner = Assert.IsType<DataFusionException>(exception.InnerException);
+        Assert.Equal(DataFusionErrorCode.Timeout, inner.ErrorCode);
+    }
+
+    [Fact]
+    public async Task ExecuteStreamAsync_WhenCancelledDuringSlowBatch_ThrowsOperationCanceled()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT count(*) FROM (SELECT * FROM generate_series(1, 100000000) ORDER BY value DESC)");
+        using var stream = await df.ExecuteStreamAsync();
+        using var cts = new CancellationTokenSource(TimeSpan.FromMilliseconds(100));
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAnyAsync<OperationCanceledException>(async () =>
+        {
+            await foreach (var _ in stream.WithCancellation(cts.Token))
+            {
+            }
+        });
+        var inner = Assert.IsType<DataFusionException>(exception.InnerException);
+        Assert.Equal(DataFusionErrorCode.Cancelled, inner.ErrorCode);
+    }
+
+    [Fact]
+    public async Task DescribeAsync_ReturnsStatisticsPerColumn()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdValueTableSelectSql(3));
+
+        // Act
+        using var described = await df.DescribeAsync();
+        using var collected = await described.CollectAsync();
+
+        // Assert
+        Assert.Equal(["describe", "id", "value"], collected.Schema.FieldsList.Select(f => f.Name).ToList());
+
+        var statistics = collected.Batches.SelectMany(b => b.Column("describe").AsString()).ToList();
+        Assert.Equal(["count", "null_count", "mean", "std", "min", "max", "median"], statistics);
+
+        var ids = collected.Batches.SelectMany(b => b.Column("id").AsDouble()).ToList();
+        Assert.Equal(3.0, ids[statistics.IndexOf("count")]);
+        Assert.Equal(2.0, ids[statistics.IndexOf("mean")]);
+        Assert.Equal(3.0, ids[statistics.IndexOf("max")]);
+    }
+
+    [Fact]
+    public async Task DescribeAsync_WhenTimesOut_ThrowsTimeout()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT * FROM generate_series(1, 100000000) ORDER BY value DESC");
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<TimeoutException>(() => df.DescribeAsync(TimeSpan.FromMilliseconds(100)));
+        var inner = Assert.IsType<DataFusionException>(exception.InnerException);
+        Assert.Equal(DataFusionErrorCode.Timeout, inner.ErrorCode);
+    }
+
+    [Fact]
+    public async Task CacheAsync_ReturnsDataFrameConsumableRepeatedly()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdValueTableSelectSql(100));
+
+        // Act
+        using var cached = await df.CacheAsync();
+
+        // Assert
+        Assert.Equal(100UL, await cached.CountAsync());
+        Assert.Equal(100UL, await cached.CountAsync());
+
+        using var collected = await cached.CollectAsync();
+        var rows = GetRows(collected.Batches);
+        var expectedRows = GetExpectedRows(100);
+        Assert.Equal(expectedRows.Select(r => r.Id), rows.Select(r => r.Id));
+    }
+
+    [Fact]
+    public async Task CacheAsync_WithinMemoryLimit_ReturnsDataFrame()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdValueTableSelectSql(100));
+
+        // Act
+        using var cached = await df.CacheAsync(memoryLimit: 1024 * 1024);
+
+        // Assert
+        Assert.Equal(100UL, await cached.CountAsync());
+    }
+
+    [Fact]
+    public async Task CacheAsync_ExceedingMemoryLimit_ThrowsResourcesExhausted()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdValueTableSelectSql(100));
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() => df.CacheAsync(memoryLimit: 1));
+        Assert.Equal(DataFusionErrorCode.ResourcesExhausted, exception.ErrorCode);
+        Assert.Contains("exceeds the memory limit of 1 bytes", exception.Message, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task CacheAsync_WhenTimesOut_ThrowsTimeout()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync("SELECT * FROM generate_series(1, 100000000) ORDER BY value DESC");
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<TimeoutException>(() => df.CacheAsync(null, TimeSpan.FromMilliseconds(100)));
+        var inner = Assert.IsType<DataFusionException>(exception.InnerException);
+        Assert.Equal(DataFusionErrorCode.Timeout, inner.ErrorCode);
+    }
+
     public void Dispose()
     {
         _context.Dispose();
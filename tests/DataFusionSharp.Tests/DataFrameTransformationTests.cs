@@ -0,0 +1,319 @@
+using DataFusionSharp.Expressions;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class DataFrameTransformationTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public DataFrameTransformationTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task SelectAsync_ProjectsColumnsAndComputedValues()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdGroupTableSelectSql(3));
+
+        // Act
+        using var selected = await df.SelectAsync(
+            Expr.Column("id"),
+            Expr.Column("id").Multiply(Expr.Literal(10)).Alias("x"),
+            Expr.Function("abs", Expr.Column("id").Negate()).Alias("a"));
+
+        // Assert
+        var schema = await selected.GetSchemaAsync();
+        Assert.Equal(["id", "x", "a"], schema.FieldsList.Select(f => f.Name).ToList());
+        Assert.Equal([10L, 20L, 30L], await GetInt64ValuesAsync(selected, "x"));
+        Assert.Equal([1L, 2L, 3L], await GetInt64ValuesAsync(selected, "a"));
+    }
+
+    [Fact]
+    public async Task FilterAsync_KeepsMatchingRows()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdGroupTableSelectSql(6));
+        var predicate = Expr.Column("id").Gt(Expr.Literal(2))
+            .And(Expr.Column("grp").NotEq(Expr.Literal(0)));
+
+        // Act
+        using var filtered = await df.FilterAsync(predicate);
+
+        // Assert
+        Assert.Equal([4L, 5L], await GetInt64ValuesAsync(filtered, "id"));
+    }
+
+    [Fact]
+    public async Task FilterAsync_UnknownColumn_ThrowsDataFusionException()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdGroupTableSelectSql(3));
+
+        // Act & Assert
+        await Assert.ThrowsAsync<DataFusionException>(() => df.FilterAsync(Expr.Column("missing").IsNull()));
+    }
+
+    [Fact]
+    public async Task AggregateAsync_EvaluatesAggregatesForEveryGroup()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdGroupTableSelectSql(6));
+
+        // Act
+        using var aggregated = await df.AggregateAsync(
+            [Expr.Column("grp")],
+            [Expr.AggregateFunction("sum", Expr.Column("id")).Alias("total"), Expr.AggregateFunction("count", Expr.Column("id")).Alias("cnt")]);
+        using var sorted = await aggregated.SortAsync(Expr.Column("grp").Sort());
+
+        // Assert
+        Assert.Equal([0L, 1L, 2L], await GetInt64ValuesAsync(sorted, "grp"));
+        Assert.Equal([9L, 5L, 7L], await GetInt64ValuesAsync(sorted, "total"));
+        Assert.Equal([2L, 2L, 2L], await GetInt64ValuesAsync(sorted, "cnt"));
+    }
+
+    [Fact]
+    public async Task SortAsync_OrdersRowsByKeysInPrecedence()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdGroupTableSelectSql(6));
+
+        // Act
+        using var sorted = await df.SortAsync(Expr.Column("grp").Sort(ascending: false), Expr.Column("id").Sort());
+
+        // Assert
+        Assert.Equal([2L, 5L, 1L, 4L, 3L, 6L], await GetInt64ValuesAsync(sorted, "id"));
+    }
+
+    [Fact]
+    public async Task LimitAsync_SkipsAndFetchesRows()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdGroupTableSelectSql(6));
+        using var sorted = await df.SortAsync(Expr.Column("id").Sort());
+
+        // Act
+        using var page = await sorted.LimitAsync(2, skip: 1);
+        using var rest = await sorted.LimitAsync(null, skip: 4);
+
+        // Assert
+        Assert.Equal([2L, 3L], await GetInt64ValuesAsync(page, "id"));
+        Assert.Equal([5L, 6L], await GetInt64ValuesAsync(rest, "id"));
+    }
+
+    [Fact]
+    public async Task WithColumnAsync_AddsAndReplacesColumns()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdGroupTableSelectSql(3));
+
+        // Act
+        using var added = await df.WithColumnAsync("doubled", Expr.Column("id").Multiply(Expr.Literal(2L)));
+        using var replaced = await added.WithColumnAsync("grp", Expr.Literal(7L));
+
+        // Assert
+        var schema = await replaced.GetSchemaAsync();
+        Assert.Equal(["id", "grp", "doubled"], schema.FieldsList.Select(f => f.Name).ToList());
+        Assert.Equal([2L, 4L, 6L], await GetInt64ValuesAsync(replaced, "doubled"));
+        Assert.Equal([7L, 7L, 7L], await GetInt64ValuesAsync(replaced, "grp"));
+    }
+
+    [Fact]
+    public async Task DropColumnsAsync_RemovesColumnsAndIgnoresUnknownNames()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdGroupTableSelectSql(3));
+
+        // Act
+        using var dropped = await df.DropColumnsAsync("grp", "missing");
+
+        // Assert
+        var schema = await dropped.GetSchemaAsync();
+        Assert.Equal(["id"], schema.FieldsList.Select(f => f.Name).ToList());
+    }
+
+    [Theory]
+    [InlineData(JoinType.Inner, new[] { 1L, 3L, 4L, 6L })]
+    [InlineData(JoinType.Left, new[] { 1L, 2L, 3L, 4L, 5L, 6L })]
+    [InlineData(JoinType.LeftSemi, new[] { 1L, 3L, 4L, 6L })]
+    [InlineData(JoinType.LeftAnti, new[] { 2L, 5L })]
+    public async Task JoinAsync_JoinsOnEqualColumns(JoinType joinType, long[] expectedIds)
+    {
+        // Arrange
+        using var left = await _context.SqlAsync(GetIdGroupTableSelectSql(6));
+        using var right = await _context.SqlAsync(GetGroupLabelTableSelectSql());
+
+        // Act
+        using var joined = await left.JoinAsync(right, joinType, ["grp"], ["grp_id"]);
+        using var sorted = await joined.SortAsync(Expr.Column("id").Sort());
+
+        // Assert
+        Assert.Equal(expectedIds, await GetInt64ValuesAsync(sorted, "id"));
+    }
+
+    [Fact]
+    public async Task JoinAsync_WithFilter_KeepsRowsMatchingFilter()
+    {
+        // Arrange
+        using var left = await _context.SqlAsync(GetIdGroupTableSelectSql(6));
+        using var right = await _context.SqlAsync(GetGroupLabelTableSelectSql());
+
+        // Act
+        using var joined = await left.JoinAsync(right, JoinType.Inner, ["grp"], ["grp_id"], Expr.Column("id").Gt(Expr.Literal(3)));
+        using var sorted = await joined.SortAsync(Expr.Column("id").Sort());
+
+        // Assert
+        var schema = await sorted.GetSchemaAsync();
+        Assert.Equal(["id", "grp", "grp_id", "label"], schema.FieldsList.Select(f => f.Name).ToList());
+        Assert.Equal([4L, 6L], await GetInt64ValuesAsync(sorted, "id"));
+    }
+
+    [Fact]
+    public async Task CrossJoinAsync_JoinsEveryRowWithEveryRow()
+    {
+        // Arrange
+        using var left = await _context.SqlAsync(GetIdGroupTableSelectSql(6));
+        using var right = await _context.SqlAsync(GetGroupLabelTableSelectSql());
+
+        // Act
+        using var joined = await left.CrossJoinAsync(right);
+
+        // Assert
+        Assert.Equal(12UL, await joined.CountAsync());
+    }
+
+    [Fact]
+    public async Task JoinAsync_DataFramesOfDifferentSessions_ThrowsInvalidArgument()
+    {
+        // Arrange
+        using var otherContext = _runtime.CreateSessionContext();
+        using var left = await _context.SqlAsync(GetIdGroupTableSelectSql(6));
+        using var right = await otherContext.SqlAsync(GetGroupLabelTableSelectSql());
+
+        // Act & Assert
+        var joinException = await Assert.ThrowsAsync<DataFusionException>(() => left.JoinAsync(right, JoinType.Inner, ["grp"], ["grp_id"]));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, joinException.ErrorCode);
+
+        var crossJoinException = await Assert.ThrowsAsync<DataFusionException>(() => left.CrossJoinAsync(right));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, crossJoinException.ErrorCode);
+    }
+
+    [Theory]
+    [InlineData("UNION ALL", new[] { 1L, 1L, 2L, 2L, 2L, 3L, 3L, 4L })]
+    [InlineData("UNION DISTINCT", new[] { 1L, 2L, 3L, 4L })]
+    [InlineData("INTERSECT ALL", new[] { 2L, 2L, 3L })]
+    [InlineData("INTERSECT DISTINCT", new[] { 2L, 3L })]
+    [InlineData("EXCEPT ALL", new[] { 1L, 1L })]
+    [InlineData("EXCEPT DISTINCT", new[] { 1L })]
+    public async Task SetOperations_CombineRows(string operation, long[] expectedIds)
+    {
+        // Arrange
+        using var left = await _context.SqlAsync(GetIdsSelectSql(1, 1, 2, 2, 3));
+        using var right = await _context.SqlAsync(GetIdsSelectSql(2, 3, 4));
+
+        // Act
+        using var combined = operation switch
+        {
+            "UNION ALL" => await left.UnionAsync(right),
+            "UNION DISTINCT" => await left.UnionDistinctAsync(right),
+            "INTERSECT ALL" => await left.IntersectAsync(right),
+            "INTERSECT DISTINCT" => await left.IntersectDistinctAsync(right),
+            "EXCEPT ALL" => await left.ExceptAsync(right),
+            "EXCEPT DISTINCT" => await left.ExceptDistinctAsync(right),
+            _ => throw new ArgumentOutOfRangeException(nameof(operation), operation, null)
+        };
+        using var sorted = await combined.SortAsync(Expr.Column("id").Sort());
+
+        // Assert
+        Assert.Equal(expectedIds, await GetInt64ValuesAsync(sorted, "id"));
+    }
+
+    [Fact]
+    public async Task UnionAsync_DifferentColumnCounts_ThrowsDataFusionExceptionNamingColumns()
+    {
+        // Arrange
+        using var left = await _context.SqlAsync(GetIdsSelectSql(1, 2));
+        using var right = await _context.SqlAsync(GetIdGroupTableSelectSql(2));
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() => left.UnionAsync(right));
+        Assert.Equal(DataFusionErrorCode.DataFrameError, exception.ErrorCode);
+        Assert.Contains("UNION ALL requires the same number of columns, but left has 1 (id) and right has 2 (id, grp)", exception.Message, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task UnionAsync_IncompatibleColumnTypes_ThrowsDataFusionException()
+    {
+        // Arrange
+        using var left = await _context.SqlAsync("SELECT CAST('2024-01-01' AS DATE) AS value");
+        using var right = await _context.SqlAsync("SELECT arrow_cast('01:00:00', 'Time64(Nanosecond)') AS value");
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() => left.UnionAsync(right));
+        Assert.Equal(DataFusionErrorCode.DataFrameError, exception.ErrorCode);
+        Assert.Contains("UNION ALL requires compatible column types", exception.Message, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task DistinctAsync_RemovesDuplicateRows()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdsSelectSql(3, 1, 1, 2, 3));
+
+        // Act
+        using var distinct = await df.DistinctAsync();
+        using var sorted = await distinct.SortAsync(Expr.Column("id").Sort());
+
+        // Assert
+        Assert.Equal([1L, 2L, 3L], await GetInt64ValuesAsync(sorted, "id"));
+    }
+
+    [Fact]
+    public async Task DistinctOnAsync_KeepsFirstRowOfEveryDistinctValueInSortOrder()
+    {
+        // Arrange
+        using var df = await _context.SqlAsync(GetIdGroupTableSelectSql(6));
+
+        // Act
+        using var distinct = await df.DistinctOnAsync(
+            [Expr.Column("grp")],
+            [Expr.Column("id"), Expr.Column("grp")],
+            [Expr.Column("grp").Sort(), Expr.Column("id").Sort(ascending: false)]);
+        using var sorted = await distinct.SortAsync(Expr.Column("grp").Sort());
+
+        // Assert
+        Assert.Equal([6L, 4L, 5L], await GetInt64ValuesAsync(sorted, "id"));
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private static string GetIdGroupTableSelectSql(int rowsCount)
+    {
+        return $"SELECT value AS id, value % 3 AS grp FROM generate_series(1, {rowsCount})";
+    }
+
+    private static string GetIdsSelectSql(params long[] ids)
+    {
+        return $"SELECT column1 AS id FROM (VALUES {string.Join(", ", ids.Select(id => $"({id})"))})";
+    }
+
+    private static string GetGroupLabelTableSelectSql()
+    {
+        return "SELECT column1 AS grp_id, column2 AS label FROM (VALUES (CAST(0 AS BIGINT), 'zero'), (CAST(1 AS BIGINT), 'one'))";
+    }
+
+    private static async Task<List<long>> GetInt64ValuesAsync(DataFrame df, string column)
+    {
+        using var collected = await df.CollectAsync();
+        return collected.Batches
+            .SelectMany(b => b.Column(column).AsInt64().Select(v => v!.Value))
+            .ToList();
+    }
+}
@@ -45,6 +45,8 @@ public abstract class FileFormatTests : IDisposable
     
     protected abstract Task RegisterTableFromPathAsync(string tableName, string path);
     
+    protected abstract Task<DataFrame> ReadCustomersAsync();
+    
     protected abstract Task WriteTableAsync(DataFrame dataFrame, string path);
     
     protected string GenerateTempFileName(string fileNamePart = "")
@@ -77,6 +79,21 @@ public abstract class FileFormatTests : IDisposable
         Assert.True(count > 0);
     }
     
+    [Fact]
+    public async Task ReadAsync_ReturnsDataFrameWithoutRegisteringTable()
+    {
+        // Act
+        using var df = await ReadCustomersAsync();
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(10UL, count);
+        await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var customers = await Context.SqlAsync("SELECT * FROM customers");
+        });
+    }
+    
     [Fact]
     public async Task QueryMultipleTables_ReturnsData()
     {
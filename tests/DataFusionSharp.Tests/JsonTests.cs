@@ -29,6 +29,11 @@ public sealed class JsonTests : FileFormatTests
         return Context.RegisterJsonAsync(tableName, path);
     }
 
+    protected override Task<DataFrame> ReadCustomersAsync()
+    {
+        return Context.ReadJsonAsync(DataSet.CustomersJsonPath);
+    }
+
     protected override Task WriteTableAsync(DataFrame dataFrame, string path)
     {
         return dataFrame.WriteJsonAsync(path);
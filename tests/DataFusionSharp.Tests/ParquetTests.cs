@@ -1,3 +1,4 @@
+using DataFusionSharp.Formats.Parquet;
 using Xunit.Abstractions;
 
 namespace DataFusionSharp.Tests;
@@ -26,8 +27,111 @@ public sealed class ParquetTests : FileFormatTests
         return Context.RegisterParquetAsync(tableName, path);
     }
 
+    protected override Task<DataFrame> ReadCustomersAsync()
+    {
+        return Context.ReadParquetAsync(DataSet.CustomersParquetPath);
+    }
+
     protected override Task WriteTableAsync(DataFrame dataFrame, string path)
     {
         return dataFrame.WriteParquetAsync(path);
     }
-}
\ No newline at end of file
+
+    [Fact]
+    public async Task RegisterParquetAsync_WithFileExtension_SpecifiesFileFilter()
+    {
+        // Arrange
+        using var tempFile = await TempInputFile.CreateAsync(".pq");
+        File.Copy(DataSet.CustomersParquetPath, tempFile.Path, overwrite: true);
+        var options = new ParquetReadOptions
+        {
+            FileExtension = ".pq"
+        };
+
+        // Act
+        await Context.RegisterParquetAsync("test", tempFile.Path, options);
+        using var df = await Context.SqlAsync("SELECT * FROM test");
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.True(count > 0);
+    }
+
+    [Fact]
+    public async Task RegisterParquetAsync_WithFileExtensionAndWrongExtension_DoesNotRegisterTable()
+    {
+        // Arrange
+        using var tempFile = await TempInputFile.CreateAsync(".parquet");
+        var options = new ParquetReadOptions
+        {
+            FileExtension = ".pq"
+        };
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            await Context.RegisterParquetAsync("test", tempFile.Path, options);
+        });
+        Assert.Contains(".pq", exception.Message, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task WriteParquetAsync_WithOptions_WritesFileSuccessfully()
+    {
+        // Arrange
+        await RegisterCustomersTableAsync();
+        using var df = await Context.SqlAsync("SELECT * FROM customers");
+        using var tempFile = await TempInputFile.CreateAsync(".parquet");
+        var parquetWriteOptions = new ParquetWriteOptions
+        {
+            Compression = "snappy",
+            MaxRowGroupSize = 2,
+            ColumnOptions = new Dictionary<string, ParquetColumnOptions>
+            {
+                ["customer_id"] = new() { BloomFilterEnabled = true }
+            }
+        };
+
+        // Act
+        await df.WriteParquetAsync(tempFile.Path, parquetWriteOptions: parquetWriteOptions);
+        await Context.RegisterParquetAsync("written", tempFile.Path);
+        using var writtenDf = await Context.SqlAsync("SELECT * FROM written");
+
+        // Assert
+        Assert.Equal(await df.CountAsync(), await writtenDf.CountAsync());
+
+        var metadata = await ReadFileMetadataAsync(tempFile.Path);
+        Assert.True(metadata.RowGroups.Count > 1, "metadata.RowGroups.Count > 1");
+        Assert.All(metadata.RowGroups, rowGroup => Assert.InRange(rowGroup.NumRows, 1, 2));
+        Assert.All(metadata.RowGroups.SelectMany(rowGroup => rowGroup.Columns),
+            column => Assert.Equal(Parquet.Meta.CompressionCodec.SNAPPY, column.MetaData!.Codec));
+    }
+
+    [Fact]
+    public async Task WriteParquetAsync_WithoutCompressionOption_UsesSessionSetting()
+    {
+        // Arrange
+        using var context = Runtime.CreateSessionContext(new SessionContextOptions
+        {
+            Options = new Dictionary<string, string> { ["datafusion.execution.parquet.compression"] = "gzip(6)" }
+        });
+        await context.RegisterParquetAsync("customers", DataSet.CustomersParquetPath);
+        using var df = await context.SqlAsync("SELECT * FROM customers");
+        using var tempFile = await TempInputFile.CreateAsync(".parquet");
+
+        // Act
+        await df.WriteParquetAsync(tempFile.Path, parquetWriteOptions: new ParquetWriteOptions { MaxRowGroupSize = 1000 });
+
+        // Assert
+        var metadata = await ReadFileMetadataAsync(tempFile.Path);
+        Assert.All(metadata.RowGroups.SelectMany(rowGroup => rowGroup.Columns),
+            column => Assert.Equal(Parquet.Meta.CompressionCodec.GZIP, column.MetaData!.Codec));
+    }
+
+    private static async Task<Parquet.Meta.FileMetaData> ReadFileMetadataAsync(string path)
+    {
+        using var reader = await Parquet.ParquetReader.CreateAsync(path);
+        Assert.NotNull(reader.Metadata);
+        return reader.Metadata;
+    }
+}
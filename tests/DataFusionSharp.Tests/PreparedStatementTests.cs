@@ -0,0 +1,88 @@
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class PreparedStatementTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public PreparedStatementTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task ExecuteAsync_WithPositionalParameters_BindsEachExecution()
+    {
+        // Arrange
+        using var statement = await _context.PrepareAsync("SELECT value FROM generate_series(1, 10) WHERE value > $1");
+
+        // Act
+        using var first = await statement.ExecuteAsync([new SqlPositionalParameter(7L)]);
+        using var second = await statement.ExecuteAsync([new SqlPositionalParameter(2L)]);
+
+        // Assert
+        Assert.Equal(3UL, await first.CountAsync());
+        Assert.Equal(8UL, await second.CountAsync());
+    }
+
+    [Fact]
+    public async Task ExecuteAsync_WithNamedParameters_ReturnsCorrectResult()
+    {
+        // Arrange
+        using var statement = await _context.PrepareAsync("SELECT value FROM generate_series(1, 10) WHERE value > $min");
+
+        // Act
+        using var df = await statement.ExecuteAsync([("min", 9L)]);
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(10L, collected.Batches[0].Column("value").AsInt64().Single());
+    }
+
+    [Fact]
+    public async Task ExecuteAsync_WithoutParameters_ReturnsResult()
+    {
+        // Arrange
+        using var statement = await _context.PrepareAsync("SELECT 1 AS id");
+
+        // Act
+        using var df = await statement.ExecuteAsync();
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(1UL, count);
+    }
+
+    [Fact]
+    public async Task GetParameterTypesAsync_ReturnsInferredTypes()
+    {
+        // Arrange
+        using var statement = await _context.PrepareAsync("SELECT value FROM generate_series(1, 10) WHERE value > $1");
+
+        // Act
+        var parameters = await statement.GetParameterTypesAsync();
+
+        // Assert
+        var parameter = Assert.Single(parameters);
+        Assert.Equal("$1", parameter.Id);
+        Assert.IsType<Int64Type>(parameter.DataType);
+    }
+
+    [Fact]
+    public async Task PrepareAsync_WithReadOnlyOptions_RejectsDdl()
+    {
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() =>
+            _context.PrepareAsync("CREATE TABLE items AS SELECT 1 AS id", SqlOptions.ReadOnly));
+        Assert.Equal(DataFusionErrorCode.StatementNotAllowed, exception.ErrorCode);
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+}
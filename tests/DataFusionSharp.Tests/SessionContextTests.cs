@@ -1,3 +1,7 @@
+using Apache.Arrow;
+using Apache.Arrow.Ipc;
+using Apache.Arrow.Types;
+
 namespace DataFusionSharp.Tests;
 
 public sealed class SessionContextTests : IDisposable
@@ -14,6 +18,124 @@ public sealed class SessionContextTests : IDisposable
         Assert.NotNull(context);
     }
 
+    [Fact]
+    public async Task CreateSessionContext_WithOptions_AppliesConfig()
+    {
+        // Arrange
+        var options = new SessionContextOptions
+        {
+            InformationSchema = true,
+            DefaultSchema = "sales",
+            Options = new Dictionary<string, string> { ["datafusion.execution.batch_size"] = "1024" }
+        };
+
+        // Act
+        using var context = _runtime.CreateSessionContext(options);
+        using var df = await context.SqlAsync(
+            "SELECT * FROM information_schema.df_settings WHERE name = 'datafusion.execution.batch_size' AND value = '1024'");
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(1UL, count);
+    }
+
+    [Fact]
+    public void CreateSessionContext_WithUnknownOption_Throws()
+    {
+        // Arrange
+        var options = new SessionContextOptions
+        {
+            Options = new Dictionary<string, string> { ["datafusion.execution.no_such_option"] = "1" }
+        };
+
+        // Act & Assert
+        var exception = Assert.Throws<DataFusionException>(() => _runtime.CreateSessionContext(options));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, exception.ErrorCode);
+    }
+
+    [Fact]
+    public async Task CreateSessionContext_WithMemoryLimit_ThrowsResourcesExhausted()
+    {
+        // Arrange
+        var options = new SessionContextOptions
+        {
+            MemoryPool = MemoryPoolType.Greedy,
+            MemoryLimit = 64 * 1024,
+            DisableSpilling = true
+        };
+        using var context = _runtime.CreateSessionContext(options);
+        using var df = await context.SqlAsync("SELECT * FROM generate_series(1, 1000000) ORDER BY value DESC");
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() => df.CollectAsync());
+        Assert.Equal(DataFusionErrorCode.ResourcesExhausted, exception.ErrorCode);
+    }
+
+    [Fact]
+    public async Task SetOptionAsync_ChangesEffectiveOption()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act
+        await context.SetOptionAsync("datafusion.execution.batch_size", "1234");
+        var options = await context.GetOptionsAsync();
+
+        // Assert
+        var batchSize = Assert.Single(options, o => o.Key == "datafusion.execution.batch_size");
+        Assert.Equal("1234", batchSize.Value);
+        Assert.NotEmpty(batchSize.Description);
+    }
+
+    [Fact]
+    public async Task SetOptionAsync_WithUnknownKey_Throws()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() => context.SetOptionAsync("datafusion.execution.no_such_option", "1"));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, exception.ErrorCode);
+    }
+
+    [Fact]
+    public async Task CollectAsync_WhenCancelled_ThrowsOperationCanceled()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        using var df = await context.SqlAsync("SELECT count(*) FROM (SELECT * FROM generate_series(1, 100000000) ORDER BY value DESC)");
+        using var cts = new CancellationTokenSource(TimeSpan.FromMilliseconds(100));
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAnyAsync<OperationCanceledException>(() => df.CollectAsync(cts.Token));
+        var inner = Assert.IsType<DataFusionException>(exception.InnerException);
+        Assert.Equal(DataFusionErrorCode.Cancelled, inner.ErrorCode);
+    }
+
+    [Fact]
+    public async Task CollectAsync_WhenTimedOut_ThrowsTimeout()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        using var df = await context.SqlAsync("SELECT count(*) FROM (SELECT * FROM generate_series(1, 100000000) ORDER BY value DESC)");
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<TimeoutException>(() => df.CollectAsync(TimeSpan.FromMilliseconds(100)));
+        var inner = Assert.IsType<DataFusionException>(exception.InnerException);
+        Assert.Equal(DataFusionErrorCode.Timeout, inner.ErrorCode);
+    }
+
+    [Fact]
+    public async Task CollectAsync_WithNonPositiveTimeout_Throws()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        using var df = await context.SqlAsync("SELECT 1");
+
+        // Act & Assert
+        await Assert.ThrowsAsync<ArgumentOutOfRangeException>(() => df.CollectAsync(TimeSpan.Zero));
+    }
+
     [Fact]
     public async Task SqlAsync_ReturnsDataFrame()
     {
@@ -120,6 +242,112 @@ public sealed class SessionContextTests : IDisposable
         Assert.True(true, "Successfully deregistered a non-existent table without throwing an exception");
     }
 
+    [Fact]
+    public async Task RegisterBatchesAsync_RegistersQueryableTable()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        var schema = new Schema.Builder()
+            .Field(f => f.Name("id").DataType(Int64Type.Default).Nullable(false))
+            .Field(f => f.Name("name").DataType(StringType.Default).Nullable(true))
+            .Build();
+        using var first = new RecordBatch(schema,
+            [new Int64Array.Builder().Append(1).Append(2).Build(), new StringArray.Builder().Append("a").Append("b").Build()], 2);
+        using var second = new RecordBatch(schema,
+            [new Int64Array.Builder().Append(3).Build(), new StringArray.Builder().AppendNull().Build()], 1);
+
+        // Act
+        await context.RegisterBatchesAsync("items", schema, [first, second]);
+
+        // Assert
+        using var df = await context.SqlAsync("SELECT sum(id) AS total, count(name) AS names FROM items");
+        using var collected = await df.CollectAsync();
+        var batch = collected.Batches[0];
+        Assert.Equal(6L, batch.Column("total").AsInt64().First());
+        Assert.Equal(2L, batch.Column("names").AsInt64().First());
+    }
+
+    [Fact]
+    public async Task RegisterStreamAsync_PullsBatchesWhenQueried()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        var stream = new CountingStream(batchCount: 3);
+
+        // Act
+        await context.RegisterStreamAsync("numbers", stream);
+        var readBeforeQuery = stream.BatchesRead;
+        using var df = await context.SqlAsync("SELECT sum(id) AS total, count(*) AS rows FROM numbers");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(0, readBeforeQuery);
+        Assert.Equal(3, stream.BatchesRead);
+        var batch = collected.Batches[0];
+        Assert.Equal(6L, batch.Column("total").AsInt64().First());
+        Assert.Equal(3L, batch.Column("rows").AsInt64().First());
+    }
+
+    [Fact]
+    public async Task RegisterStreamAsync_SecondQuery_ThrowsInvalidArgument()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        await context.RegisterStreamAsync("numbers", new CountingStream(batchCount: 2));
+        using var first = await context.SqlAsync("SELECT sum(id) AS total FROM numbers");
+        using var collected = await first.CollectAsync();
+
+        // Act
+        using var second = await context.SqlAsync("SELECT sum(id) AS total FROM numbers");
+        var exception = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var _ = await second.CollectAsync();
+        });
+
+        // Assert
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, exception.ErrorCode);
+        Assert.Contains("already been scanned", exception.Message);
+    }
+
+    [Fact]
+    public async Task RegisterViewAsync_ReevaluatesDataFrameOnEveryQuery()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        await ExecuteAsync(context, "CREATE TABLE items (id BIGINT)");
+        await ExecuteAsync(context, "INSERT INTO items VALUES (1), (2)");
+        using var source = await context.SqlAsync("SELECT id FROM items WHERE id > 1");
+
+        // Act
+        await context.RegisterViewAsync("big_items", source);
+        await ExecuteAsync(context, "INSERT INTO items VALUES (3)");
+        using var df = await context.SqlAsync("SELECT count(*) AS total FROM big_items");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(2L, collected.Batches[0].Column("total").AsInt64().First());
+        Assert.Equal(2UL, await source.CountAsync());
+    }
+
+    [Fact]
+    public async Task RegisterTableAsync_MaterializesDataFrameOnce()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        await ExecuteAsync(context, "CREATE TABLE items (id BIGINT)");
+        await ExecuteAsync(context, "INSERT INTO items VALUES (1), (2)");
+        using var source = await context.SqlAsync("SELECT id FROM items WHERE id > 1");
+
+        // Act
+        await context.RegisterTableAsync("big_items", source);
+        await ExecuteAsync(context, "INSERT INTO items VALUES (3)");
+        using var df = await context.SqlAsync("SELECT count(*) AS total FROM big_items");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(1L, collected.Batches[0].Column("total").AsInt64().First());
+    }
+
     [Fact]
     public async Task SqlAsync_WithNamedParameters_ReturnsCorrectResult()
     {
@@ -174,8 +402,252 @@ public sealed class SessionContextTests : IDisposable
         Assert.DoesNotContain("$value_a", ex.Message, StringComparison.Ordinal);
     }
 
+    [Fact]
+    public async Task CatalogManagement_CreateListDrop_RoundTrips()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act
+        await context.CreateCatalogAsync("sales");
+        await context.CreateSchemaAsync("sales", "eu");
+        using (await context.SqlAsync("CREATE TABLE sales.eu.items AS SELECT 1 AS id"))
+        {
+        }
+
+        // Assert
+        Assert.Equal(["datafusion", "sales"], await context.ListCatalogsAsync());
+        Assert.Equal(["eu"], await context.ListSchemasAsync("sales"));
+        Assert.Equal(["items"], await context.ListTablesAsync("sales", "eu"));
+        Assert.True(await context.TableExistsAsync("sales.eu.items"));
+        Assert.False(await context.TableExistsAsync("sales.eu.missing"));
+
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() => context.DropSchemaAsync("sales", "eu"));
+        Assert.Equal(DataFusionErrorCode.CatalogError, exception.ErrorCode);
+
+        await context.DropSchemaAsync("sales", "eu", cascade: true);
+        await context.DropCatalogAsync("sales");
+        Assert.Equal(["datafusion"], await context.ListCatalogsAsync());
+        Assert.False(await context.TableExistsAsync("sales.eu.items"));
+    }
+
+    [Fact]
+    public async Task DropCatalogAsync_WithSchemas_RequiresCascade()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        await context.CreateCatalogAsync("sales");
+        await context.CreateSchemaAsync("sales", "eu");
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() => context.DropCatalogAsync("sales"));
+        Assert.Equal(DataFusionErrorCode.CatalogError, exception.ErrorCode);
+
+        await context.DropCatalogAsync("sales", cascade: true);
+        Assert.Equal(["datafusion"], await context.ListCatalogsAsync());
+    }
+
+    [Fact]
+    public async Task DropCatalogAsync_DefaultCatalog_Throws()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() => context.DropCatalogAsync("datafusion", cascade: true));
+        Assert.Equal(DataFusionErrorCode.CatalogError, exception.ErrorCode);
+    }
+
+    [Fact]
+    public async Task SqlAsync_WithPositionalParameters_ReturnsCorrectResult()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act
+        using var df = await context.SqlAsync(
+            "SELECT $1 + $2 AS result, $3 AS greeting",
+            [new SqlPositionalParameter(1L), new SqlPositionalParameter(2L), new SqlPositionalParameter("hello")]
+        );
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var batch = collected.Batches[0];
+        Assert.Equal(3L, batch.Column("result").AsInt64().First());
+        Assert.Equal("hello", batch.Column("greeting").AsString().First());
+    }
+
+    [Fact]
+    public async Task GetParameterTypesAsync_ReturnsInferredTypes()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act
+        var parameters = await context.GetParameterTypesAsync(
+            "SELECT value, $2 AS unknown FROM generate_series(1, 10) WHERE value > $1");
+
+        // Assert
+        Assert.Collection(parameters,
+            p =>
+            {
+                Assert.Equal("$1", p.Id);
+                Assert.IsType<Int64Type>(p.DataType);
+            },
+            p =>
+            {
+                Assert.Equal("$2", p.Id);
+                Assert.Null(p.DataType);
+            });
+    }
+
+    [Fact]
+    public async Task SqlAsync_WithReadOnlyOptions_RejectsDdl()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await context.SqlAsync("CREATE TABLE items AS SELECT 1 AS id", SqlOptions.ReadOnly);
+        });
+        Assert.Equal(DataFusionErrorCode.StatementNotAllowed, exception.ErrorCode);
+    }
+
+    [Fact]
+    public async Task SqlAsync_WithReadOnlyOptions_ExecutesQuery()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act
+        using var df = await context.SqlAsync("SELECT 1 AS id", SqlOptions.ReadOnly);
+        var count = await df.CountAsync();
+
+        // Assert
+        Assert.Equal(1UL, count);
+    }
+
+    [Fact]
+    public async Task GetParameterTypesAsync_WithReadOnlyOptions_RejectsDdl()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() =>
+            context.GetParameterTypesAsync("CREATE TABLE items AS SELECT $1 AS id", SqlOptions.ReadOnly));
+        Assert.Equal(DataFusionErrorCode.StatementNotAllowed, exception.ErrorCode);
+    }
+
+    [Fact]
+    public async Task SetDialectAsync_ChangesParsingOfQueries()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act
+        await context.SetDialectAsync("postgresql");
+
+        // Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await context.SqlAsync("SELECT 1 AS `my col`");
+        });
+        Assert.Equal(DataFusionErrorCode.SqlError, exception.ErrorCode);
+    }
+
+    [Fact]
+    public async Task SetDialectAsync_WithUnknownDialect_Throws()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(() => context.SetDialectAsync("klingon"));
+        Assert.Equal(DataFusionErrorCode.InvalidArgument, exception.ErrorCode);
+    }
+
+    [Fact]
+    public async Task SqlAsync_WithDialectOption_OverridesSessionDialect()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        await context.SetDialectAsync("postgresql");
+
+        // Act
+        using var df = await context.SqlAsync("SELECT 1 AS `my col`", new SqlOptions { Dialect = "mysql" });
+        var schema = await df.GetSchemaAsync();
+
+        // Assert
+        Assert.Equal("my col", schema.FieldsList[0].Name);
+    }
+
+    [Fact]
+    public async Task SqlScriptAsync_ExecutesAllStatementsAndReturnsLast()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        const string script = """
+            CREATE TABLE items (id BIGINT);
+            INSERT INTO items VALUES (1), (2);
+            SELECT sum(id) AS total FROM items;
+            """;
+
+        // Act
+        using var df = await context.SqlScriptAsync(script);
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(3L, collected.Batches[0].Column("total").AsInt64().First());
+    }
+
+    [Fact]
+    public async Task SqlScriptAsync_WithFailingStatement_ReportsStatementPosition()
+    {
+        // Arrange
+        using var context = _runtime.CreateSessionContext();
+        const string script = "SELECT 1;\nSELECT * FROM missing_table";
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await context.SqlScriptAsync(script);
+        });
+        Assert.Contains("Statement 2 at line 2, column 1", exception.Message, StringComparison.Ordinal);
+    }
+
     public void Dispose()
     {
         _runtime.Dispose();
     }
+
+    private static async Task ExecuteAsync(SessionContext context, string sql)
+    {
+        using var df = await context.SqlAsync(sql);
+        using var _ = await df.CollectAsync();
+    }
+
+    private sealed class CountingStream(int batchCount) : IArrowArrayStream
+    {
+        public Schema Schema { get; } = new Schema.Builder()
+            .Field(f => f.Name("id").DataType(Int64Type.Default).Nullable(false))
+            .Build();
+
+        public int BatchesRead { get; private set; }
+
+        public ValueTask<RecordBatch?> ReadNextRecordBatchAsync(CancellationToken cancellationToken = default)
+        {
+            if (BatchesRead == batchCount)
+                return ValueTask.FromResult<RecordBatch?>(null);
+
+            BatchesRead++;
+            return ValueTask.FromResult<RecordBatch?>(new RecordBatch(Schema, [new Int64Array.Builder().Append(BatchesRead).Build()], 1));
+        }
+
+        public void Dispose()
+        {
+        }
+    }
 }
\ No newline at end of file
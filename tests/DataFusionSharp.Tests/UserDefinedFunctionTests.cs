@@ -0,0 +1,192 @@
+using Apache.Arrow;
+using Apache.Arrow.Ipc;
+using Apache.Arrow.Types;
+
+namespace DataFusionSharp.Tests;
+
+public sealed class UserDefinedFunctionTests : IDisposable
+{
+    private readonly DataFusionRuntime _runtime;
+    private readonly SessionContext _context;
+
+    public UserDefinedFunctionTests()
+    {
+        _runtime = DataFusionRuntime.Create();
+        _context = _runtime.CreateSessionContext();
+    }
+
+    [Fact]
+    public async Task RegisterScalarUdfAsync_FunctionIsCallableFromSql()
+    {
+        // Arrange
+        await _context.RegisterScalarUdfAsync("add_one", [Int64Type.Default], Int64Type.Default, AddOne);
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT add_one(value) AS result FROM generate_series(1, 3) ORDER BY result");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal([2L, 3L, 4L], collected.Batches.SelectMany(b => b.Column("result").AsInt64()).ToList());
+    }
+
+    [Fact]
+    public async Task RegisterScalarUdfAsync_WhenFunctionThrows_FailsQueryWithMessage()
+    {
+        // Arrange
+        await _context.RegisterScalarUdfAsync("fail", [Int64Type.Default], Int64Type.Default, _ => throw new InvalidOperationException("boom"));
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await _context.SqlAsync("SELECT fail(value) FROM generate_series(1, 3)");
+            using var _ = await df.CollectAsync();
+        });
+        Assert.Contains("InvalidOperationException: boom", exception.Message, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task RegisterAggregateUdfAsync_AggregatesGroups()
+    {
+        // Arrange
+        await _context.RegisterAggregateUdfAsync("my_sum", [Int64Type.Default], Int64Type.Default, [Int64Type.Default], () => new SumAccumulator());
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT value % 2 AS parity, my_sum(value) AS total FROM generate_series(1, 10) GROUP BY parity ORDER BY parity");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal([30L, 25L], collected.Batches.SelectMany(b => b.Column("total").AsInt64()).ToList());
+    }
+
+    [Fact]
+    public async Task RegisterAggregateUdfAsync_WithoutArguments_CountsRows()
+    {
+        // Arrange
+        await _context.RegisterAggregateUdfAsync("my_count", [], Int64Type.Default, [Int64Type.Default], () => new SumAccumulator(countRows: true));
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT my_count() AS total FROM generate_series(1, 42)");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal(42L, collected.Batches[0].Column("total").AsInt64().First());
+    }
+
+    [Fact]
+    public async Task RegisterAggregateUdfAsync_WithoutArguments_RejectsArguments()
+    {
+        // Arrange
+        await _context.RegisterAggregateUdfAsync("my_count", [], Int64Type.Default, [Int64Type.Default], () => new SumAccumulator(countRows: true));
+
+        // Act & Assert
+        var exception = await Assert.ThrowsAsync<DataFusionException>(async () =>
+        {
+            using var df = await _context.SqlAsync("SELECT my_count(true) AS total FROM generate_series(1, 42)");
+            using var _ = await df.CollectAsync();
+        });
+        Assert.Contains("Function 'my_count' takes no arguments", exception.Message, StringComparison.Ordinal);
+    }
+
+    [Fact]
+    public async Task RegisterWindowUdfAsync_EvaluatesWholePartitions()
+    {
+        // Arrange
+        await _context.RegisterWindowUdfAsync("running_total", [Int64Type.Default], Int64Type.Default, RunningTotal);
+
+        // Act
+        const string sql = """
+            SELECT running_total(value) OVER (PARTITION BY value % 2 ORDER BY value) AS total
+            FROM generate_series(1, 6)
+            ORDER BY value
+            """;
+        using var df = await _context.SqlAsync(sql);
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        Assert.Equal([1L, 2L, 4L, 6L, 9L, 12L], collected.Batches.SelectMany(b => b.Column("total").AsInt64()).ToList());
+    }
+
+    [Fact]
+    public async Task RegisterTableFunctionAsync_StreamsRowsForArguments()
+    {
+        // Arrange
+        await _context.RegisterTableFunctionAsync("numbers", args => new NumbersStream(args.Column(0).AsInt64().First()!.Value));
+
+        // Act
+        using var df = await _context.SqlAsync("SELECT count(*) AS rows, sum(n) AS total FROM numbers(4)");
+        using var collected = await df.CollectAsync();
+
+        // Assert
+        var batch = collected.Batches[0];
+        Assert.Equal(4L, batch.Column("rows").AsInt64().First());
+        Assert.Equal(10L, batch.Column("total").AsInt64().First());
+    }
+
+    public void Dispose()
+    {
+        _context.Dispose();
+        _runtime.Dispose();
+    }
+
+    private static Int64Array AddOne(RecordBatch args)
+    {
+        var builder = new Int64Array.Builder();
+        foreach (var value in args.Column(0).AsInt64())
+        {
+            if (value is { } v)
+                builder.Append(v + 1);
+            else
+                builder.AppendNull();
+        }
+
+        return builder.Build();
+    }
+
+    private static Int64Array RunningTotal(RecordBatch args)
+    {
+        var builder = new Int64Array.Builder();
+        var total = 0L;
+        foreach (var value in args.Column(0).AsInt64())
+            builder.Append(total += value ?? 0);
+
+        return builder.Build();
+    }
+
+    private sealed class NumbersStream(long count) : IArrowArrayStream
+    {
+        private bool _read;
+
+        public Schema Schema { get; } = new Schema.Builder()
+            .Field(f => f.Name("n").DataType(Int64Type.Default).Nullable(false))
+            .Build();
+
+        public ValueTask<RecordBatch?> ReadNextRecordBatchAsync(CancellationToken cancellationToken = default)
+        {
+            if (_read)
+                return ValueTask.FromResult<RecordBatch?>(null);
+
+            _read = true;
+            var builder = new Int64Array.Builder();
+            for (var i = 1L; i <= count; i++)
+                builder.Append(i);
+            return ValueTask.FromResult<RecordBatch?>(new RecordBatch(Schema, [builder.Build()], (int)count));
+        }
+
+        public void Dispose()
+        {
+        }
+    }
+
+    private sealed class SumAccumulator(bool countRows = false) : IAccumulator
+    {
+        private long _total;
+
+        public void Update(RecordBatch arguments) => _total += countRows ? arguments.Length : arguments.Column(0).AsInt64().Sum(v => v ?? 0);
+
+        public void Merge(RecordBatch states) => _total += states.Column(0).AsInt64().Sum(v => v ?? 0);
+
+        public IReadOnlyList<IArrowArray> GetState() => [Evaluate()];
+
+        public IArrowArray Evaluate() => new Int64Array.Builder().Append(_total).Build();
+    }
+}